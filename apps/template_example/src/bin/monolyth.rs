@@ -1,4 +1,4 @@
-use std::{sync::LazyLock, time::Duration};
+use std::{process::ExitCode, sync::OnceLock, time::Duration};
 
 // use lib::bootstrap::instrumentation::stdout;
 use lib::bootstrap::{
@@ -6,28 +6,71 @@ use lib::bootstrap::{
     instrumentation::opentelemetry::Otel,
 };
 use template_example::{
-    AppConfig, bootstrappers::api::rest::PublicApi, modules::Modules,
+    AppConfig,
+    bootstrappers::{
+        api::rest::PublicApi, feature_flags::FeatureFlagRefresher,
+        jobs::JobQueueWorker, outbox::OutboxRelayWorker, scheduler::Scheduler,
+    },
+    modules::Modules,
 };
 
 configure_allocator!();
 
-static CONFIG: LazyLock<AppConfig> = LazyLock::new(AppConfig::load);
+static CONFIG: OnceLock<AppConfig> = OnceLock::new();
+
+#[expect(
+    clippy::print_stderr,
+    reason = "tracing is not set up until the configuration is loaded"
+)]
+fn main() -> ExitCode {
+    // SAFETY: runs at the top of `main`, before the runtime spawns any worker
+    // threads.
+    match unsafe { AppConfig::try_load() } {
+        Ok(config) => run(CONFIG.get_or_init(|| config)),
+        Err(report) => {
+            eprintln!("{report}");
+            ExitCode::FAILURE
+        },
+    }
+}
 
 #[tokio::main]
-async fn main() {
+async fn run(config: &'static AppConfig) -> ExitCode {
     // // Without opentelemetry
-    // stdout::wrap(bootstrap!(
-    //     [PublicApi(&CONFIG.server)],
-    //     Modules::init(&CONFIG.modules)
+    // let result = stdout::wrap(bootstrap!(
+    //     [
+    //         PublicApi(&config.server),
+    //         OutboxRelayWorker(&config.outbox),
+    //         Scheduler(&config.scheduler),
+    //         JobQueueWorker(&config.jobs),
+    //         FeatureFlagRefresher(&config.feature_flags)
+    //     ],
+    //     Modules::init(&config.modules),
+    //     shutdown: &config.shutdown
     // ))
     // .await;
 
     // With opentelemetry
-    Otel::from(&CONFIG.otel)
+    let result = Otel::from(&config.otel)
         .with_timeout(Duration::from_secs(30))
         .wrap(bootstrap!(
-            [PublicApi(&CONFIG.server)],
-            Modules::init(&CONFIG.modules)
+            [
+                PublicApi(&config.server),
+                OutboxRelayWorker(&config.outbox),
+                Scheduler(&config.scheduler),
+                JobQueueWorker(&config.jobs),
+                FeatureFlagRefresher(&config.feature_flags)
+            ],
+            Modules::init(&config.modules),
+            shutdown: &config.shutdown
         ))
         .await;
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            error.report();
+            ExitCode::FAILURE
+        },
+    }
 }
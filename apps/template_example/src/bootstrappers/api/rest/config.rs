@@ -1,6 +1,7 @@
 use std::net::{IpAddr, SocketAddr};
 
 use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
 
 #[derive(FromEnv)]
 #[env(prefix = "SERVER_")]
@@ -14,6 +15,27 @@ pub struct RestApiConfig {
     pub request_timeout_ms: u64,
     #[env(default = "1048576")]
     pub body_limit_bytes: usize,
+    #[env(default = "86400")]
+    pub idempotency_ttl_secs: u64,
+    #[env(default = "60")]
+    pub idempotency_lock_ttl_secs: u64,
+    #[env(default = "0")]
+    pub trusted_proxy_hops: usize,
+    #[env(default = "10")]
+    pub sign_in_rate_limit_per_minute: u32,
+    #[env(default = "5")]
+    pub sign_up_rate_limit_per_minute: u32,
+    #[env(default = "60")]
+    pub list_users_rate_limit_per_minute: u32,
+}
+
+impl Validate for RestApiConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .port("SERVER_PORT", self.port)
+            .non_empty("SERVER_DOMAIN", &self.domain)
+            .positive("SERVER_REQUEST_TIMEOUT_MS", self.request_timeout_ms);
+    }
 }
 
 impl From<&RestApiConfig> for SocketAddr {
@@ -1,19 +1,39 @@
 use axum::Router;
 use entrait::Impl;
-use lib::presentation::api::rest::health::{live, ready};
+use lib::presentation::api::rest::{
+    health::{detailed_history, history, live, ready},
+    required_permission,
+};
 
-use crate::modules::Modules;
+use crate::{
+    features::user_auth::presentation::api::rest::extractors::session::{
+        UserSession,
+    },
+    modules::{Modules, READ_HEALTH_HISTORY},
+};
 
 pub const LIVE_PATH: &str = "/health/live";
 pub const READY_PATH: &str = "/health/ready";
+pub const HISTORY_PATH: &str = "/health/history";
+pub const CHECKS_HISTORY_PATH: &str = "/health/history/checks";
+
+required_permission!(ReadHealthHistory = READ_HEALTH_HISTORY);
 
 pub fn router() -> Router<Impl<Modules>> {
     Router::new()
         .route(LIVE_PATH, live())
         .route(READY_PATH, ready::<Modules>())
+        .route(HISTORY_PATH, history::<Modules>())
+        .route(
+            CHECKS_HISTORY_PATH,
+            detailed_history::<Modules, UserSession, ReadHealthHistory>(),
+        )
 }
 
 #[must_use]
 pub fn is_health_route(path: &str) -> bool {
-    matches!(path, LIVE_PATH | READY_PATH)
+    matches!(
+        path,
+        LIVE_PATH | READY_PATH | HISTORY_PATH | CHECKS_HISTORY_PATH
+    )
 }
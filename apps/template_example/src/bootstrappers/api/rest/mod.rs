@@ -1,39 +1,72 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 
 use entrait::Impl;
 use lib::{
+    application::{
+        clock::SharedClock,
+        di::Has as _,
+        feature_flag::FeatureFlags,
+        rate_limit::{Quota, RateLimiter},
+    },
     async_trait,
     axum::{
         extract::DefaultBodyLimit,
-        http::{HeaderValue, Method, header},
+        http::{
+            HeaderName, HeaderValue, Method, header,
+            header::InvalidHeaderValue,
+        },
     },
     axum_otel_metrics::{HttpMetricsLayerBuilder, PathSkipper},
-    bootstrap::Bootstrapper,
-    presentation::api::rest::startup::RestApi,
+    bootstrap::{Bootstrapper, Shutdown},
+    infrastructure::persistence::{
+        RedisPool, idempotency::RedisIdempotencyStore,
+        rate_limit::RedisRateLimitStore, redis::Namespace,
+    },
+    presentation::api::rest::{
+        feature_flag::{EvaluateFor, FeatureGate},
+        idempotency::{self, Idempotency},
+        rate_limit::{self, KeyBy, RateLimit},
+        startup::{RestApi, RestApiError},
+    },
     tower_http::cors::CorsLayer,
 };
 
 pub use self::{config::RestApiConfig, openapi::ApiDoc, routes::router};
-use crate::modules::Modules;
+use crate::{
+    features::{
+        user::application::feature_flag::USER_LISTING,
+        user_auth::presentation::api::rest::extractors::session::UserSession,
+    },
+    modules::Modules,
+};
 
 mod config;
 mod health;
 mod openapi;
 mod routes;
 
+#[derive(thiserror::Error, Debug)]
+pub enum PublicApiError {
+    #[error("`SERVER_DOMAIN` is not a valid CORS origin: {0}")]
+    InvalidDomain(#[from] InvalidHeaderValue),
+
+    #[error(transparent)]
+    Server(#[from] RestApiError),
+}
+
 pub struct PublicApi;
 
 #[async_trait]
 impl Bootstrapper for PublicApi {
     type Config = RestApiConfig;
     type Modules = Modules;
+    type Error = PublicApiError;
 
-    async fn bootstrap(config: &Self::Config, deps: &Impl<Modules>) {
+    async fn bootstrap(
+        config: &Self::Config,
+        deps: &Impl<Modules>,
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error> {
         let metric_layer = HttpMetricsLayerBuilder::new()
             .with_skipper(PathSkipper::new(|path| {
                 RestApi::is_openapi_route(path) || health::is_health_route(path)
@@ -44,9 +77,7 @@ impl Bootstrapper for PublicApi {
             CorsLayer::very_permissive()
         } else {
             CorsLayer::new()
-                .allow_origin(config.domain.parse::<HeaderValue>().expect(
-                    "`DEPLOY_DOMAIN` value should be parseable `HeaderValue`",
-                ))
+                .allow_origin(config.domain.parse::<HeaderValue>()?)
                 .allow_methods([
                     Method::GET,
                     Method::POST,
@@ -58,6 +89,13 @@ impl Bootstrapper for PublicApi {
                     header::AUTHORIZATION,
                     header::ACCEPT,
                     header::ACCEPT_ENCODING,
+                    HeaderName::from_static(idempotency::HEADER),
+                ])
+                .expose_headers([
+                    header::RETRY_AFTER,
+                    HeaderName::from_static(rate_limit::LIMIT_HEADER),
+                    HeaderName::from_static(rate_limit::REMAINING_HEADER),
+                    HeaderName::from_static(rate_limit::RESET_HEADER),
                 ])
                 .allow_credentials(true)
         };
@@ -69,11 +107,60 @@ impl Bootstrapper for PublicApi {
             .layer(DefaultBodyLimit::max(config.body_limit_bytes))
             .merge(health::router());
 
+        let redis: &RedisPool = deps.get_dependency();
+        let namespace: &Namespace = deps.get_dependency();
+        let idempotency_store =
+            RedisIdempotencyStore::new(redis.clone(), namespace);
+        let idempotency = Idempotency::new(idempotency_store)
+            .ttl(Duration::from_secs(config.idempotency_ttl_secs))
+            .lock_ttl(Duration::from_secs(config.idempotency_lock_ttl_secs))
+            .max_body_bytes(config.body_limit_bytes)
+            .trusted_proxy_hops(config.trusted_proxy_hops);
+
+        let rate_limit_store =
+            RedisRateLimitStore::new(redis.clone(), namespace);
+        let rate_limit = RateLimit::new(RateLimiter::new(rate_limit_store))
+            .trusted_proxy_hops(config.trusted_proxy_hops)
+            .route(
+                Method::POST,
+                "/user/auth/sign-in",
+                Quota::per_minute(config.sign_in_rate_limit_per_minute),
+                KeyBy::client_ip(),
+            )
+            .route(
+                Method::POST,
+                "/user/auth/sign-up",
+                Quota::per_minute(config.sign_up_rate_limit_per_minute),
+                KeyBy::client_ip(),
+            )
+            .route(
+                Method::GET,
+                "/user/users",
+                Quota::per_minute(config.list_users_rate_limit_per_minute),
+                KeyBy::subject::<UserSession>(),
+            );
+
+        let feature_flags: &FeatureFlags = deps.get_dependency();
+        let feature_gate = FeatureGate::new(feature_flags.clone()).route(
+            Method::GET,
+            "/user/users",
+            USER_LISTING,
+            EvaluateFor::subject::<UserSession>(),
+        );
+
+        let clock: &SharedClock = deps.get_dependency();
+
         RestApi::builder(router, deps)
             .with_cors(cors_layer)
+            .with_clock(clock.clone())
+            .with_idempotency(idempotency)
+            .with_rate_limit(rate_limit)
+            .with_feature_gate(feature_gate)
             .with_openapi(openapi)
             .build()
-            .run(SocketAddr::from(config))
-            .await;
+            .run(SocketAddr::from(config), &shutdown)
+            .await?;
+
+        Ok(())
     }
 }
@@ -11,7 +11,7 @@ use utoipa::{
     },
 };
 
-use crate::shared::presentation::api::rest::B2C_TAG;
+use crate::shared::presentation::api::rest::{B2C_TAG, USER_SECURITY};
 
 #[derive(OpenApiDerive)]
 #[openapi(
@@ -48,6 +48,6 @@ impl Modify for SecurityModifier {
                 .build(),
         );
 
-        components.add_security_scheme("user", scheme);
+        components.add_security_scheme(USER_SECURITY, scheme);
     }
 }
@@ -6,6 +6,7 @@ use utoipa::OpenApi as _;
 
 use super::ApiDoc;
 use crate::features::{
+    audit::{AuditFeature, presentation::api::rest::audit_router},
     user::{UserFeature, presentation::api::rest::user_router},
     user_auth::{UserAuthFeature, presentation::api::rest::user_auth_router},
 };
@@ -13,8 +14,9 @@ use crate::features::{
 #[must_use]
 pub fn router<App>() -> OpenApiRouter<App>
 where
-    App: Application + UserAuthFeature + UserFeature,
+    App: Application + UserAuthFeature + UserFeature + AuditFeature,
 {
     OpenApiRouter::with_openapi(ApiDoc::openapi())
         .nest("/user", user_router().nest("/auth", user_auth_router()))
+        .nest("/audit", audit_router())
 }
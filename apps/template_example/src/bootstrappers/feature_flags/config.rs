@@ -0,0 +1,18 @@
+use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
+
+#[derive(FromEnv)]
+#[env(prefix = "FLAGS_")]
+pub struct FeatureFlagRefresherConfig {
+    #[env(default = "30")]
+    pub refresh_interval_secs: u64,
+}
+
+impl Validate for FeatureFlagRefresherConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.positive(
+            "FLAGS_REFRESH_INTERVAL_SECS",
+            self.refresh_interval_secs,
+        );
+    }
+}
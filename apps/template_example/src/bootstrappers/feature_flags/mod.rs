@@ -0,0 +1,40 @@
+use std::{convert::Infallible, time::Duration};
+
+use entrait::Impl;
+use lib::{
+    application::{di::Has as _, feature_flag::FeatureFlags},
+    async_trait,
+    bootstrap::{Bootstrapper, Shutdown},
+};
+
+pub use self::config::FeatureFlagRefresherConfig;
+use crate::modules::Modules;
+
+mod config;
+
+pub struct FeatureFlagRefresher;
+
+#[async_trait]
+impl Bootstrapper for FeatureFlagRefresher {
+    type Config = FeatureFlagRefresherConfig;
+    type Modules = Modules;
+    type Error = Infallible;
+
+    async fn bootstrap(
+        config: &Self::Config,
+        deps: &Impl<Modules>,
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error> {
+        let feature_flags: &FeatureFlags = deps.get_dependency();
+
+        feature_flags
+            .clone()
+            .run_refresh(
+                Duration::from_secs(config.refresh_interval_secs),
+                shutdown,
+            )
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
+
+#[derive(FromEnv)]
+#[env(prefix = "JOBS_")]
+pub struct JobQueueConfig {
+    #[env(default = "4")]
+    pub concurrency: usize,
+    #[env(default = "1000")]
+    pub poll_interval_ms: u64,
+    #[env(default = "300")]
+    pub visibility_timeout_secs: u64,
+}
+
+impl Validate for JobQueueConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .check(
+                "JOBS_CONCURRENCY",
+                self.concurrency > 0,
+                "must be greater than zero",
+            )
+            .positive("JOBS_POLL_INTERVAL_MS", self.poll_interval_ms)
+            .positive(
+                "JOBS_VISIBILITY_TIMEOUT_SECS",
+                self.visibility_timeout_secs,
+            );
+    }
+}
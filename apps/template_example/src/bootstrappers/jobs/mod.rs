@@ -0,0 +1,47 @@
+use std::{convert::Infallible, time::Duration};
+
+use entrait::Impl;
+use lib::{
+    application::di::Has as _,
+    async_trait,
+    bootstrap::{Bootstrapper, Shutdown},
+    infrastructure::persistence::{SqlxPool, postgres::queue::JobWorker},
+};
+use sqlx::Postgres;
+
+pub use self::config::JobQueueConfig;
+use crate::{
+    features::user::application::job::SendWelcomeEmailHandler,
+    modules::Modules,
+};
+
+mod config;
+
+pub struct JobQueueWorker;
+
+#[async_trait]
+impl Bootstrapper for JobQueueWorker {
+    type Config = JobQueueConfig;
+    type Modules = Modules;
+    type Error = Infallible;
+
+    async fn bootstrap(
+        config: &Self::Config,
+        deps: &Impl<Modules>,
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error> {
+        let postgres: &SqlxPool<Postgres> = deps.get_dependency();
+
+        JobWorker::new(postgres.clone())
+            .concurrency(config.concurrency)
+            .poll_interval(Duration::from_millis(config.poll_interval_ms))
+            .visibility_timeout(Duration::from_secs(
+                config.visibility_timeout_secs,
+            ))
+            .handler(SendWelcomeEmailHandler)
+            .run(deps, shutdown)
+            .await;
+
+        Ok(())
+    }
+}
@@ -1 +1,5 @@
 pub mod api;
+pub mod feature_flags;
+pub mod jobs;
+pub mod outbox;
+pub mod scheduler;
@@ -0,0 +1,30 @@
+use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
+
+#[derive(FromEnv)]
+#[env(prefix = "OUTBOX_")]
+pub struct OutboxRelayConfig {
+    #[env(default = "1000")]
+    pub poll_interval_ms: u64,
+    #[env(default = "100")]
+    pub batch_size: i64,
+    #[env(default = "10")]
+    pub max_attempts: i32,
+}
+
+impl Validate for OutboxRelayConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .positive("OUTBOX_POLL_INTERVAL_MS", self.poll_interval_ms)
+            .check(
+                "OUTBOX_BATCH_SIZE",
+                self.batch_size > 0,
+                "must be greater than zero",
+            )
+            .check(
+                "OUTBOX_MAX_ATTEMPTS",
+                self.max_attempts > 0,
+                "must be greater than zero",
+            );
+    }
+}
@@ -0,0 +1,45 @@
+use std::{convert::Infallible, time::Duration};
+
+use entrait::Impl;
+use lib::{
+    application::{di::Has as _, event::EventBus},
+    async_trait,
+    bootstrap::{Bootstrapper, Shutdown},
+    infrastructure::persistence::{SqlxPool, postgres::outbox::OutboxRelay},
+};
+use sqlx::Postgres;
+
+pub use self::config::OutboxRelayConfig;
+use self::sink::EventBusSink;
+use crate::modules::Modules;
+
+mod config;
+mod sink;
+
+pub struct OutboxRelayWorker;
+
+#[async_trait]
+impl Bootstrapper for OutboxRelayWorker {
+    type Config = OutboxRelayConfig;
+    type Modules = Modules;
+    type Error = Infallible;
+
+    async fn bootstrap(
+        config: &Self::Config,
+        deps: &Impl<Modules>,
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error> {
+        let postgres: &SqlxPool<Postgres> = deps.get_dependency();
+        let events: &EventBus = deps.get_dependency();
+
+        OutboxRelay::new(postgres.clone())
+            .poll_interval(Duration::from_millis(config.poll_interval_ms))
+            .batch_size(config.batch_size)
+            .max_attempts(config.max_attempts)
+            .sink(EventBusSink::from(events))
+            .run(shutdown)
+            .await;
+
+        Ok(())
+    }
+}
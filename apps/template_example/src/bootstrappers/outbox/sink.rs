@@ -0,0 +1,35 @@
+use lib::{
+    application::event::{DomainEvent, EventBus},
+    async_trait,
+    infrastructure::persistence::postgres::outbox::{
+        OutboxMessage, OutboxSink, SinkResult,
+    },
+};
+
+use crate::features::user::application::event::UserCreated;
+
+pub struct EventBusSink(EventBus);
+
+impl From<&EventBus> for EventBusSink {
+    fn from(events: &EventBus) -> Self {
+        Self(events.clone())
+    }
+}
+
+#[async_trait]
+impl OutboxSink for EventBusSink {
+    async fn deliver(&self, message: &OutboxMessage) -> SinkResult {
+        match message.event_type.as_str() {
+            UserCreated::NAME => self.0.publish(
+                message
+                    .decode::<UserCreated>()
+                    .map_err(|error| error.to_string())?,
+            ),
+            event_type => {
+                tracing::warn!(event_type, "no route for outbox event");
+            },
+        }
+
+        Ok(())
+    }
+}
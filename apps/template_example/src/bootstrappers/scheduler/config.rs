@@ -0,0 +1,22 @@
+use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
+
+#[derive(FromEnv)]
+#[env(prefix = "SCHEDULER_")]
+pub struct SchedulerConfig {
+    #[env(default = "0 3 * * *")]
+    pub outbox_cleanup_cron: String,
+    #[env(default = "168")]
+    pub outbox_retention_hours: u64,
+    #[env(default = "60000")]
+    pub jitter_ms: u64,
+}
+
+impl Validate for SchedulerConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.cron(
+            "SCHEDULER_OUTBOX_CLEANUP_CRON",
+            &self.outbox_cleanup_cron,
+        );
+    }
+}
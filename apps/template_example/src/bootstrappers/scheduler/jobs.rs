@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use entrait::Impl;
+use lib::{
+    application::di::Has as _,
+    async_trait,
+    bootstrap::scheduler::{Job, JobResult},
+    infrastructure::persistence::{SqlxPool, postgres::outbox},
+};
+use sqlx::Postgres;
+
+use crate::modules::Modules;
+
+pub struct OutboxCleanup {
+    pub retention: Duration,
+}
+
+#[async_trait]
+impl Job<Modules> for OutboxCleanup {
+    async fn run(&self, deps: &Impl<Modules>) -> JobResult {
+        let postgres: &SqlxPool<Postgres> = deps.get_dependency();
+
+        let purged = outbox::purge_delivered(postgres, self.retention)
+            .await
+            .map_err(|error| error.to_string())?;
+
+        tracing::info!(purged, "purged delivered outbox messages");
+
+        Ok(())
+    }
+}
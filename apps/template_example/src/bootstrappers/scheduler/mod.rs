@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use lib::bootstrap::scheduler::{self, CronError, JobScheduler, Schedule};
+
+pub use self::config::SchedulerConfig;
+use self::jobs::OutboxCleanup;
+use crate::modules::Modules;
+
+mod config;
+mod jobs;
+
+pub type Scheduler = scheduler::Scheduler<AppJobs>;
+
+pub struct AppJobs;
+
+impl scheduler::Jobs for AppJobs {
+    type Config = SchedulerConfig;
+    type Modules = Modules;
+
+    fn register(
+        config: &Self::Config,
+        scheduler: JobScheduler<Modules>,
+    ) -> Result<JobScheduler<Modules>, CronError> {
+        let jitter = Duration::from_millis(config.jitter_ms);
+
+        Ok(scheduler.job(
+            "outbox_cleanup",
+            Schedule::cron(&config.outbox_cleanup_cron)?.jitter(jitter),
+            OutboxCleanup {
+                retention: Duration::from_secs(
+                    config.outbox_retention_hours.saturating_mul(3600),
+                ),
+            },
+        ))
+    }
+}
@@ -1,14 +1,49 @@
 use fromenv::FromEnv;
-use lib::bootstrap::instrumentation::opentelemetry::OtelConfig;
+use lib::bootstrap::{
+    config::{Validate, Validator},
+    instrumentation::opentelemetry::OtelConfig,
+    shutdown::ShutdownConfig,
+};
 
-use crate::{bootstrappers::api::rest::RestApiConfig, modules::ModulesConfig};
+use crate::{
+    bootstrappers::{
+        api::rest::RestApiConfig, feature_flags::FeatureFlagRefresherConfig,
+        jobs::JobQueueConfig, outbox::OutboxRelayConfig,
+        scheduler::SchedulerConfig,
+    },
+    modules::ModulesConfig,
+};
 
 #[derive(FromEnv)]
 pub struct AppConfig {
     #[env(nested)]
     pub server: RestApiConfig,
     #[env(nested)]
+    pub outbox: OutboxRelayConfig,
+    #[env(nested)]
+    pub scheduler: SchedulerConfig,
+    #[env(nested)]
+    pub jobs: JobQueueConfig,
+    #[env(nested)]
+    pub feature_flags: FeatureFlagRefresherConfig,
+    #[env(nested)]
     pub modules: ModulesConfig,
     #[env(nested)]
     pub otel: OtelConfig,
+    #[env(nested)]
+    pub shutdown: ShutdownConfig,
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .nested(&self.server)
+            .nested(&self.outbox)
+            .nested(&self.scheduler)
+            .nested(&self.jobs)
+            .nested(&self.feature_flags)
+            .nested(&self.modules)
+            .nested(&self.otel)
+            .nested(&self.shutdown);
+    }
 }
@@ -0,0 +1,3 @@
+use lib::application::audit::AuditAction;
+
+pub const READ_AUDIT_LOG: AuditAction = AuditAction::new("audit:read");
@@ -0,0 +1,3 @@
+use lib::application::authorization::Permission;
+
+pub const READ_AUDIT_LOG: Permission = Permission::new("audit:read");
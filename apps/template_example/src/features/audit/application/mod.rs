@@ -0,0 +1,5 @@
+pub use self::usecase::AuditUseCases;
+
+pub mod action;
+pub mod authorization;
+pub mod usecase;
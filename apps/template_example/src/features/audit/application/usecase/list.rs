@@ -0,0 +1,47 @@
+use entrait::entrait;
+use lib::{
+    application::{
+        audit::{
+            Actor, AuditEvent, AuditFilter, AuditLog, AuditRecord,
+            HasAuditExt as _,
+        },
+        authorization::{HasAuthorizationExt as _, Policy},
+        di::Has,
+    },
+    domain::pagination::{Page, PageRequest},
+};
+use tracing::instrument;
+
+use super::{AuditUseCaseError, AuditUseCaseResult};
+use crate::features::{
+    audit::application::{action, authorization::READ_AUDIT_LOG},
+    user_auth::domain::session::entity::SessionEntity,
+};
+
+#[entrait(pub ListAuditRecordsUsecase)]
+#[instrument(skip(deps))]
+async fn list_audit_records<Deps>(
+    deps: &Deps,
+    principal: &SessionEntity,
+    filter: AuditFilter,
+    request: PageRequest,
+) -> AuditUseCaseResult<Page<AuditRecord>>
+where
+    Deps: Has<AuditLog> + Has<Policy>,
+{
+    deps.authorize(principal, READ_AUDIT_LOG)?;
+
+    let audit_log: &AuditLog = deps.get_dependency();
+    let page = audit_log
+        .query(filter, request)
+        .await
+        .map_err(AuditUseCaseError::Store)?;
+
+    deps.audit(AuditEvent::new(
+        Actor::from(*principal),
+        action::READ_AUDIT_LOG,
+    ))
+    .await;
+
+    Ok(page)
+}
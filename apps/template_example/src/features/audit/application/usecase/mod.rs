@@ -0,0 +1,18 @@
+use lib::application::{application_result, authorization::AuthorizationError};
+
+pub use self::list::ListAuditRecordsUsecase;
+
+mod list;
+
+pub trait AuditUseCases = ListAuditRecordsUsecase;
+
+#[derive(thiserror::Error, Debug)]
+pub enum AuditUseCaseError {
+    #[error("{0}")]
+    Store(String),
+
+    #[error(transparent)]
+    Forbidden(#[from] AuthorizationError),
+}
+
+application_result!(AuditUseCase);
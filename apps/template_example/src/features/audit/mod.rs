@@ -0,0 +1,6 @@
+use self::application::AuditUseCases;
+
+pub mod application;
+pub mod presentation;
+
+pub trait AuditFeature = AuditUseCases;
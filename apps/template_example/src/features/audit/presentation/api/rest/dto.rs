@@ -0,0 +1,83 @@
+use lib::{
+    application::audit::{AuditFilter, AuditOutcome, AuditRecord},
+    uuid::Uuid,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct AuditRecordDto {
+    id: Uuid,
+
+    occurred_at: String,
+
+    actor_kind: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor_id: Option<Uuid>,
+
+    action: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_kind: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_id: Option<String>,
+
+    #[schema(examples("success", "failure"))]
+    outcome: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<Uuid>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip: Option<String>,
+
+    #[schema(value_type = Object)]
+    details: Value,
+}
+
+impl From<AuditRecord> for AuditRecordDto {
+    fn from(record: AuditRecord) -> Self {
+        let (target_kind, target_id) = record
+            .target
+            .map(|target| (target.kind, target.id))
+            .unzip();
+
+        Self {
+            id: record.id.value,
+            occurred_at: record.occurred_at.to_rfc3339(),
+            actor_kind: record.actor.kind().to_owned(),
+            actor_id: record.actor.id(),
+            action: record.action,
+            target_kind,
+            target_id,
+            outcome: record.outcome.as_str().to_owned(),
+            request_id: record.request_id,
+            ip: record.ip.map(|ip| ip.to_string()),
+            details: record.details,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams, Default, Debug)]
+#[into_params(parameter_in = Query)]
+pub struct AuditFilterQuery {
+    actor: Option<Uuid>,
+
+    action: Option<String>,
+
+    #[param(value_type = Option<String>)]
+    outcome: Option<AuditOutcome>,
+}
+
+impl From<AuditFilterQuery> for AuditFilter {
+    fn from(query: AuditFilterQuery) -> Self {
+        Self {
+            actor: query.actor,
+            action: query.action,
+            outcome: query.outcome,
+        }
+    }
+}
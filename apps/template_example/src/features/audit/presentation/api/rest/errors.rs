@@ -0,0 +1,34 @@
+use axum::http::StatusCode;
+use serde_json::json;
+
+use crate::{
+    features::audit::application::usecase::AuditUseCaseError,
+    shared::presentation::api::rest::ApiError,
+};
+
+impl From<AuditUseCaseError> for ApiError {
+    fn from(error: AuditUseCaseError) -> Self {
+        let (status_code, error_code, error, details) = {
+            use AuditUseCaseError as E;
+            match error {
+                E::Store(_) => Self::internal_server_error(error),
+
+                E::Forbidden(forbidden) => (
+                    StatusCode::FORBIDDEN,
+                    "FORBIDDEN",
+                    error.to_string(),
+                    json!({
+                        "permission": forbidden.permission()
+                    }),
+                ),
+            }
+        };
+
+        Self::UseCase {
+            status_code,
+            error_code,
+            message: error,
+            details,
+        }
+    }
+}
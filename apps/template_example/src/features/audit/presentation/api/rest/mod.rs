@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod errors;
+pub mod routes;
+
+pub use routes::router as audit_router;
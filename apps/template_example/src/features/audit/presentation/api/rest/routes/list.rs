@@ -0,0 +1,82 @@
+use axum::{
+    extract::{OriginalUri, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use lib::{
+    application::{authorization::Policy, di::Has},
+    presentation::api::rest::{
+        authorization::Authorized,
+        errors::JsonError,
+        pagination::{PageDto, PageQuery},
+        required_permission,
+        response::ResponseExt as _,
+        validation::parseable::Parseable as _,
+    },
+    tap::Pipe as _,
+};
+use tracing::instrument;
+
+use crate::{
+    features::{
+        audit::{
+            application::{
+                authorization::READ_AUDIT_LOG,
+                usecase::ListAuditRecordsUsecase,
+            },
+            presentation::api::rest::dto::{AuditFilterQuery, AuditRecordDto},
+        },
+        user_auth::presentation::api::rest::extractors::session::UserSession,
+    },
+    shared::presentation::api::rest::{
+        ApiError, B2C_TAG,
+        errors::{BadRequestResponse, ValidationFailedResponse},
+        extractors::{Json, Query},
+    },
+};
+
+required_permission!(pub ReadAuditLog = READ_AUDIT_LOG);
+
+#[utoipa::path(
+    get,
+    path = "/records",
+    tag = B2C_TAG,
+    params(PageQuery, AuditFilterQuery),
+    responses(
+        (status = OK, body = PageDto<AuditRecordDto>),
+        (status = UNAUTHORIZED, body = JsonError),
+        (status = FORBIDDEN, body = JsonError),
+        ValidationFailedResponse,
+        BadRequestResponse
+    ),
+)]
+#[instrument(skip(app))]
+pub async fn list_audit_records<App>(
+    app: State<App>,
+    Authorized {
+        principal: user_session,
+        ..
+    }: Authorized<UserSession, ReadAuditLog>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<PageQuery>,
+    Query(filter): Query<AuditFilterQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    App: ListAuditRecordsUsecase + Has<Policy>,
+{
+    let request = query.parse()?;
+
+    app.list_audit_records(&user_session.entity, filter.into(), request)
+        .await?
+        .pipe(|page| {
+            PageDto::<AuditRecordDto>::from_page(
+                page,
+                request.limit,
+                uri.path(),
+            )
+        })
+        .pipe(Json)
+        .into_response()
+        .with_status(StatusCode::OK)
+        .pipe(Ok)
+}
@@ -0,0 +1,24 @@
+use lib::{
+    application::{authorization::Policy, di::Has},
+    presentation::api::rest::routes::Application,
+    utoipa_axum::{router::OpenApiRouter, routes},
+};
+
+use crate::{
+    features::{
+        audit::AuditFeature,
+        user_auth::application::usecase::session::GetSessionFromTokenUsecase,
+    },
+    shared::presentation::api::rest::secured,
+};
+
+pub mod list;
+
+pub fn router<App>() -> OpenApiRouter<App>
+where
+    App: Application + AuditFeature + GetSessionFromTokenUsecase + Has<Policy>,
+{
+    OpenApiRouter::new().routes(secured::<list::ReadAuditLog, _>(routes!(
+        list::list_audit_records::<App>
+    )))
+}
@@ -1,2 +1,3 @@
+pub mod audit;
 pub mod user;
 pub mod user_auth;
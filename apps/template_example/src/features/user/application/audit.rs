@@ -0,0 +1,5 @@
+use lib::application::audit::AuditAction;
+
+pub const SIGN_UP: AuditAction = AuditAction::new("user:sign_up");
+
+pub const SIGN_IN: AuditAction = AuditAction::new("user:sign_in");
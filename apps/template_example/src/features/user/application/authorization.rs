@@ -0,0 +1,17 @@
+use lib::application::authorization::{Permission, Resource};
+
+use crate::features::{
+    user::domain::User, user_auth::domain::session::entity::SessionEntity,
+};
+
+pub const READ_USER: Permission = Permission::new("user:read");
+
+pub const LIST_USERS: Permission = Permission::new("user:list");
+
+impl Resource<SessionEntity> for User {
+    fn is_owned_by(&self, principal: &SessionEntity) -> bool {
+        match principal {
+            SessionEntity::User(id, _) => *id == self.id,
+        }
+    }
+}
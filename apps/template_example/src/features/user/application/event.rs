@@ -0,0 +1,25 @@
+use std::{convert::Infallible, fmt::Display, hash::Hash};
+
+use lib::{application::event::DomainEvent, domain::Id};
+use serde::{Deserialize, Serialize};
+
+use crate::features::user::domain::User;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UserCreated {
+    pub id: Id<User>,
+}
+
+impl DomainEvent for UserCreated {
+    const NAME: &'static str = "user.created";
+
+    fn aggregate_id(&self) -> impl Display + Hash {
+        self.id
+    }
+}
+
+pub async fn log_user_created(event: UserCreated) -> Result<(), Infallible> {
+    tracing::info!(user_id = %event.id, "user has been created");
+
+    Ok(())
+}
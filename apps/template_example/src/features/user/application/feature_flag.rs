@@ -0,0 +1,3 @@
+use lib::application::feature_flag::FeatureFlag;
+
+pub const USER_LISTING: FeatureFlag = FeatureFlag::new("user_listing");
@@ -0,0 +1,49 @@
+use lib::{
+    application::outbox::QueuedJob,
+    async_trait,
+    domain::Id,
+    infrastructure::persistence::postgres::queue::{JobHandler, JobResult},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::features::user::{
+    application::repository::UserRepository, domain::User,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SendWelcomeEmail {
+    pub user_id: Id<User>,
+}
+
+impl QueuedJob for SendWelcomeEmail {
+    const NAME: &'static str = "user.send_welcome_email";
+}
+
+pub struct SendWelcomeEmailHandler;
+
+#[async_trait]
+impl<D> JobHandler<D> for SendWelcomeEmailHandler
+where
+    D: UserRepository + Sync,
+{
+    type Job = SendWelcomeEmail;
+
+    async fn handle(&self, job: SendWelcomeEmail, deps: &D) -> JobResult {
+        let Some(user) = deps
+            .find_user_by_id(job.user_id)
+            .await
+            .map_err(|error| error.to_string())?
+        else {
+            tracing::warn!(user_id = %job.user_id, "user no longer exists");
+            return Ok(());
+        };
+
+        tracing::info!(
+            user_id = %user.id,
+            email = %user.email,
+            "sending welcome email"
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 use self::repository::UserRepository;
 pub use self::usecase::UserUseCases;
 
+pub mod audit;
+pub mod authorization;
+pub mod event;
+pub mod feature_flag;
+pub mod job;
 pub mod repository;
 pub mod usecase;
 
@@ -1,5 +1,12 @@
 use entrait::entrait;
-use lib::{anyhow::Result, async_trait, domain::Id};
+use lib::{
+    anyhow::Result,
+    async_trait,
+    domain::{
+        Id,
+        pagination::{Page, PageRequest},
+    },
+};
 
 use crate::{
     features::user::domain::{CreateUser, User},
@@ -22,4 +29,6 @@ pub trait UserRepository {
     async fn find_user_by_id(&self, id: Id<User>) -> Result<Option<User>>;
 
     async fn find_user_by_email(&self, email: &Email) -> Result<Option<User>>;
+
+    async fn list_users(&self, request: PageRequest) -> Result<Page<User>>;
 }
@@ -1,9 +1,16 @@
 use entrait::entrait;
+use lib::application::{
+    audit::{Actor, AuditEvent, AuditLog, HasAuditExt as _},
+    di::Has,
+};
 use tracing::instrument;
 
 use super::{UserUseCaseError, UserUseCaseResult};
 use crate::features::{
-    user::{application::repository::UserRepository, domain::User},
+    user::{
+        application::{audit::SIGN_IN, repository::UserRepository},
+        domain::User,
+    },
     user_auth::{
         application::service::secret_hasher::SecretHasherService,
         domain::session::CreateSession,
@@ -17,16 +24,36 @@ async fn authorize_user<Deps>(
     source: CreateSession,
 ) -> UserUseCaseResult<User>
 where
-    Deps: UserRepository + SecretHasherService,
+    Deps: UserRepository + SecretHasherService + Has<AuditLog>,
 {
     let user = UserRepository::find_user_by_email(deps, &source.email).await?;
 
-    SecretHasherService::verify_secret(
+    let verified = SecretHasherService::verify_secret(
         deps,
         &source.password,
-        user.as_ref().map(|u| &u.password_hash),
+        user.as_ref().and_then(|u| u.password_hash.as_ref()),
     )
-    .map_err(|_| UserUseCaseError::InvalidPassword)?;
+    .is_ok();
+
+    match user {
+        Some(user) if verified => {
+            deps.audit(
+                AuditEvent::new(Actor::user(user.id), SIGN_IN)
+                    .target("user", user.id),
+            )
+            .await;
+
+            Ok(user)
+        },
+        user => {
+            let event = AuditEvent::new(Actor::Anonymous, SIGN_IN).failed();
+            let event = match user {
+                Some(user) => event.target("user", user.id),
+                None => event,
+            };
+            deps.audit(event).await;
 
-    user.ok_or(UserUseCaseError::InvalidPassword)
+            Err(UserUseCaseError::InvalidPassword)
+        },
+    }
 }
@@ -1,11 +1,23 @@
 use entrait::entrait;
-use lib::{domain::Id, tap::Pipe as _};
+use lib::{
+    application::{
+        audit::{Actor, AuditEvent, AuditLog, HasAuditExt as _},
+        di::Has,
+        id::{HasIdGeneratorExt as _, SharedIdGenerator},
+        outbox::{HasOutboxExt as _, SharedOutbox},
+        transaction::UnitOfWork,
+    },
+    tap::Pipe as _,
+};
 use tracing::instrument;
 
 use super::{UserUseCaseError, UserUseCaseResult};
 use crate::features::{
     user::{
-        application::repository::UserRepository,
+        application::{
+            audit::SIGN_UP, event::UserCreated, job::SendWelcomeEmail,
+            repository::UserRepository,
+        },
         domain::{CreateUser, User},
     },
     user_auth::application::service::secret_hasher::SecretHasherService,
@@ -18,19 +30,52 @@ async fn create_user<Deps>(
     source: CreateUser,
 ) -> UserUseCaseResult<User>
 where
-    Deps: UserRepository + SecretHasherService,
+    Deps: UserRepository
+        + SecretHasherService
+        + UnitOfWork
+        + Has<AuditLog>
+        + Has<SharedIdGenerator>
+        + Has<SharedOutbox>,
 {
-    if UserRepository::find_user_by_email(deps, &source.email)
-        .await?
-        .is_some()
-    {
-        return UserUseCaseError::EmailAlreadyUsed(source.email).pipe(Err);
-    }
-
-    let password_hash =
-        SecretHasherService::hash_secret(deps, &source.password)?;
-
-    UserRepository::create_user(deps, Id::generate(), source, password_hash)
-        .await?
-        .pipe(Ok)
+    let user = deps
+        .transaction(async {
+            if UserRepository::find_user_by_email(deps, &source.email)
+                .await?
+                .is_some()
+            {
+                return UserUseCaseError::EmailAlreadyUsed(source.email)
+                    .pipe(Err);
+            }
+
+            let password_hash =
+                SecretHasherService::hash_secret(deps, &source.password)?;
+
+            let user = UserRepository::create_user(
+                deps,
+                deps.generate_id(),
+                source,
+                password_hash,
+            )
+            .await?;
+
+            deps.enqueue(&UserCreated {
+                id: user.id,
+            })
+            .await?;
+
+            deps.enqueue_job(&SendWelcomeEmail {
+                user_id: user.id,
+            })
+            .await?;
+
+            Ok(user)
+        })
+        .await?;
+
+    deps.audit(
+        AuditEvent::new(Actor::user(user.id), SIGN_UP).target("user", user.id),
+    )
+    .await;
+
+    Ok(user)
 }
@@ -1,22 +1,37 @@
 use entrait::entrait;
-use lib::domain::Id;
+use lib::{
+    application::{
+        authorization::{HasAuthorizationExt as _, Policy},
+        di::Has,
+    },
+    domain::Id,
+};
 use tracing::instrument;
 
 use super::{UserUseCaseError, UserUseCaseResult};
-use crate::features::user::{
-    application::repository::UserRepository, domain::User,
+use crate::features::{
+    user::{
+        application::{authorization::READ_USER, repository::UserRepository},
+        domain::User,
+    },
+    user_auth::domain::session::entity::SessionEntity,
 };
 
 #[entrait(pub GetUserByIdUsecase)]
 #[instrument(skip(deps))]
 async fn get_user_by_id<Deps>(
     deps: &Deps,
+    principal: &SessionEntity,
     id: Id<User>,
 ) -> UserUseCaseResult<User>
 where
-    Deps: UserRepository,
+    Deps: UserRepository + Has<Policy>,
 {
-    UserRepository::find_user_by_id(deps, id)
+    let user = UserRepository::find_user_by_id(deps, id)
         .await?
-        .ok_or(UserUseCaseError::NotFoundById(id))
+        .ok_or(UserUseCaseError::NotFoundById(id))?;
+
+    deps.authorize_on(principal, READ_USER, &user)?;
+
+    Ok(user)
 }
@@ -0,0 +1,36 @@
+use entrait::entrait;
+use lib::{
+    application::{
+        authorization::{HasAuthorizationExt as _, Policy},
+        di::Has,
+    },
+    domain::pagination::{Page, PageRequest},
+    tap::Pipe as _,
+};
+use tracing::instrument;
+
+use super::UserUseCaseResult;
+use crate::features::{
+    user::{
+        application::{
+            authorization::LIST_USERS, repository::UserRepository,
+        },
+        domain::User,
+    },
+    user_auth::domain::session::entity::SessionEntity,
+};
+
+#[entrait(pub ListUsersUsecase)]
+#[instrument(skip(deps))]
+async fn list_users<Deps>(
+    deps: &Deps,
+    principal: &SessionEntity,
+    request: PageRequest,
+) -> UserUseCaseResult<Page<User>>
+where
+    Deps: UserRepository + Has<Policy>,
+{
+    deps.authorize(principal, LIST_USERS)?;
+
+    UserRepository::list_users(deps, request).await?.pipe(Ok)
+}
@@ -1,8 +1,15 @@
-use lib::{application::application_result, domain::Id};
+use lib::{
+    application::{
+        application_result, authorization::AuthorizationError,
+        outbox::OutboxError, transaction::TransactionError,
+    },
+    domain::Id,
+};
 
 pub use self::{
     authorize::AuthorizeUserUsecase, create::CreateUserUsecase,
     find_by_id::FindUserByIdUsecase, get_by_id::GetUserByIdUsecase,
+    list::ListUsersUsecase,
 };
 use crate::{features::user::domain::User, shared::domain::email::Email};
 
@@ -10,17 +17,25 @@ mod authorize;
 mod create;
 mod find_by_id;
 mod get_by_id;
+mod list;
 
 pub trait UserUseCases = AuthorizeUserUsecase
     + CreateUserUsecase
     + FindUserByIdUsecase
-    + GetUserByIdUsecase;
+    + GetUserByIdUsecase
+    + ListUsersUsecase;
 
 #[derive(thiserror::Error, Debug)]
 pub enum UserUseCaseError {
     #[error(transparent)]
     Infrastructure(#[from] lib::anyhow::Error),
 
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+
+    #[error(transparent)]
+    Outbox(#[from] OutboxError),
+
     #[error("user with the specified email already exists")]
     EmailAlreadyUsed(Email),
 
@@ -32,6 +47,9 @@ pub enum UserUseCaseError {
 
     #[error("invalid password")]
     InvalidPassword,
+
+    #[error(transparent)]
+    Forbidden(#[from] AuthorizationError),
 }
 
 application_result!(UserUseCase);
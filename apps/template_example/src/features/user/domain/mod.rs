@@ -1,8 +1,8 @@
 use lib::domain::Id;
 
 use self::{
-    avatar_url::UserAvatarUrl, name::UserName, surname::UserSurname,
-    target_settings::UserTargetSettings,
+    avatar_url::UserAvatarUrl, name::UserName, role::UserRole,
+    surname::UserSurname, target_settings::UserTargetSettings,
 };
 use crate::shared::domain::{
     email::Email,
@@ -12,6 +12,7 @@ use crate::shared::domain::{
 pub mod avatar_url;
 mod constraints;
 pub mod name;
+pub mod role;
 pub mod surname;
 pub mod target_settings;
 
@@ -21,7 +22,8 @@ pub struct User {
     pub name: UserName,
     pub surname: UserSurname,
     pub email: Email,
-    pub password_hash: PasswordHash,
+    pub password_hash: Option<PasswordHash>,
+    pub role: UserRole,
     pub avatar_url: Option<UserAvatarUrl>,
     pub target_settings: UserTargetSettings,
 }
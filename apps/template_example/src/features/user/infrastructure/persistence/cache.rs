@@ -0,0 +1,148 @@
+use entrait::entrait;
+use lib::{
+    anyhow::Result,
+    application::{cache::CachePolicy, di::Has},
+    async_trait,
+    domain::{
+        Id,
+        pagination::{Page, PageRequest},
+    },
+    infrastructure::persistence::{
+        RedisPool, SqlxPool, cache::HasReadThroughCacheExt as _,
+        entity::DomainTypeFromDb, redis::Namespace,
+    },
+    instrument_all,
+    tap::Pipe as _,
+    uuid::Uuid,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::Postgres;
+
+use crate::{
+    features::user::{
+        application::repository::UserRepositoryImpl,
+        domain::{CreateUser, User},
+        infrastructure::persistence::postgres::entity::{
+            role::StoredUserRole, target_settings::StoredUserTargetSettings,
+        },
+    },
+    shared::{
+        domain::{email::Email, password::PasswordHash},
+        infrastructure::persistence::{
+            CachedPostgresRepositoryImpl, PostgresRepositoryImpl,
+        },
+    },
+};
+
+type UserCachePolicy = CachePolicy<CachedPostgresRepositoryImpl>;
+
+#[derive(Serialize, Deserialize)]
+struct CachedUser {
+    id: Uuid,
+    name: String,
+    surname: String,
+    email: String,
+    role: StoredUserRole,
+    avatar_url: Option<String>,
+    target_settings: StoredUserTargetSettings,
+}
+
+impl From<User> for CachedUser {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.value,
+            name: user.name.into(),
+            surname: user.surname.into(),
+            email: user.email.into(),
+            role: user.role.into(),
+            avatar_url: user.avatar_url.map(Into::into),
+            target_settings: user.target_settings.into(),
+        }
+    }
+}
+
+impl From<CachedUser> for User {
+    fn from(user: CachedUser) -> Self {
+        Self {
+            id: user.id.into(),
+            name: user.name.into_domain(),
+            surname: user.surname.into_domain(),
+            email: user.email.into_domain(),
+            password_hash: None,
+            role: user.role.into(),
+            avatar_url: user.avatar_url.map(DomainTypeFromDb::into_domain),
+            target_settings: user.target_settings.into(),
+        }
+    }
+}
+
+#[entrait]
+#[async_trait]
+#[instrument_all]
+impl UserRepositoryImpl for CachedPostgresRepositoryImpl {
+    async fn create_user<App>(
+        app: &App,
+        id: Id<User>,
+        source: CreateUser,
+        password_hash: PasswordHash,
+    ) -> Result<User>
+    where
+        App: Has<SqlxPool<Postgres>>
+            + Has<RedisPool>
+            + Has<Namespace>
+            + Has<UserCachePolicy>
+            + Sync,
+    {
+        let user =
+            PostgresRepositoryImpl::create_user(app, id, source, password_hash)
+                .await?;
+
+        let policy: &UserCachePolicy = app.get_dependency();
+        app.invalidate(policy, &[&user.id.value.to_string()]).await;
+
+        Ok(user)
+    }
+
+    async fn find_user_by_id<App>(
+        app: &App,
+        id: Id<User>,
+    ) -> Result<Option<User>>
+    where
+        App: Has<SqlxPool<Postgres>>
+            + Has<RedisPool>
+            + Has<Namespace>
+            + Has<UserCachePolicy>
+            + Sync,
+    {
+        let policy: &UserCachePolicy = app.get_dependency();
+
+        app.read_through(policy, &id.value.to_string(), move || async move {
+            PostgresRepositoryImpl::find_user_by_id(app, id)
+                .await
+                .map(|user| user.map(CachedUser::from))
+        })
+        .await?
+        .map(User::from)
+        .pipe(Ok)
+    }
+
+    async fn find_user_by_email<App>(
+        app: &App,
+        email: &Email,
+    ) -> Result<Option<User>>
+    where
+        App: Has<SqlxPool<Postgres>>,
+    {
+        PostgresRepositoryImpl::find_user_by_email(app, email).await
+    }
+
+    async fn list_users<App>(
+        app: &App,
+        request: PageRequest,
+    ) -> Result<Page<User>>
+    where
+        App: Has<SqlxPool<Postgres>>,
+    {
+        PostgresRepositoryImpl::list_users(app, request).await
+    }
+}
@@ -0,0 +1,101 @@
+use std::ops::Deref;
+
+use entrait::entrait;
+use lib::{
+    anyhow::Result,
+    application::di::Has,
+    async_trait,
+    domain::{
+        DomainType, Id,
+        pagination::{Page, PageRequest},
+    },
+    tap::Pipe as _,
+    testkit::table::InMemoryTable,
+};
+
+use crate::{
+    features::user::{
+        application::repository::UserRepositoryImpl,
+        domain::{CreateUser, User},
+        infrastructure::persistence::postgres::entity::{
+            StoredUser, role::StoredUserRole,
+        },
+    },
+    shared::{
+        domain::{email::Email, password::PasswordHash},
+        infrastructure::persistence::InMemoryRepositoryImpl,
+    },
+};
+
+#[derive(Clone, Default)]
+pub struct UserTable(InMemoryTable<StoredUser>);
+
+impl Deref for UserTable {
+    type Target = InMemoryTable<StoredUser>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[entrait]
+#[async_trait]
+impl UserRepositoryImpl for InMemoryRepositoryImpl {
+    async fn create_user<App>(
+        app: &App,
+        id: Id<User>,
+        source: CreateUser,
+        password_hash: PasswordHash,
+    ) -> Result<User>
+    where
+        App: Has<UserTable>,
+    {
+        let user = StoredUser {
+            id: id.value,
+            name: source.name.into_inner(),
+            surname: source.surname.into_inner(),
+            email: source.email.into_inner(),
+            password_hash: password_hash.0.expose_secret().to_owned(),
+            role: StoredUserRole::User,
+            avatar_url: source.avatar_url.map(DomainType::into_inner),
+            target_settings: source.target_settings.into(),
+        };
+
+        app.get_dependency().insert(id, user.clone());
+
+        Ok(user.into())
+    }
+
+    async fn find_user_by_id<App>(
+        app: &App,
+        id: Id<User>,
+    ) -> Result<Option<User>>
+    where
+        App: Has<UserTable>,
+    {
+        app.get_dependency().get(id).map(User::from).pipe(Ok)
+    }
+
+    async fn find_user_by_email<App>(
+        app: &App,
+        email: &Email,
+    ) -> Result<Option<User>>
+    where
+        App: Has<UserTable>,
+    {
+        app.get_dependency()
+            .find(|user| user.email == email.as_ref())
+            .map(User::from)
+            .pipe(Ok)
+    }
+
+    async fn list_users<App>(
+        app: &App,
+        request: PageRequest,
+    ) -> Result<Page<User>>
+    where
+        App: Has<UserTable>,
+    {
+        app.get_dependency().page(&request).map(User::from).pipe(Ok)
+    }
+}
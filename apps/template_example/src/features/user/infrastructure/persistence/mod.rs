@@ -1 +1,4 @@
+pub mod cache;
+#[cfg(feature = "testkit")]
+pub mod in_memory;
 pub mod postgres;
@@ -2,12 +2,13 @@ use lib::{infrastructure::persistence::entity::DomainTypeFromDb, uuid::Uuid};
 use model_mapper::Mapper;
 use sqlx::FromRow;
 
+pub mod role;
 pub mod target_settings;
 
-use self::target_settings::StoredUserTargetSettings;
+use self::{role::StoredUserRole, target_settings::StoredUserTargetSettings};
 use crate::features::user::domain::User;
 
-#[derive(Mapper, FromRow, Debug)]
+#[derive(Mapper, FromRow, Clone, Debug)]
 #[mapper(derive(ty = User, into))]
 pub struct StoredUser {
     pub id: Uuid,
@@ -23,7 +24,9 @@ pub struct StoredUser {
         when(ty = User, into_with = DomainTypeFromDb::into_domain),
     )]
     pub email: String,
+    #[mapper(when(ty = User, into_with = Some(password_hash.into())))]
     pub password_hash: String,
+    pub role: StoredUserRole,
     #[mapper(
         when(ty = User, opt(into_with = DomainTypeFromDb::into_domain)),
     )]
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+
+use crate::features::user::domain::role::UserRole;
+
+#[derive(Type, Serialize, Deserialize, Clone, Copy, Debug)]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+pub enum StoredUserRole {
+    User,
+    Admin,
+}
+
+impl From<UserRole> for StoredUserRole {
+    fn from(role: UserRole) -> Self {
+        match role {
+            UserRole::User => Self::User,
+            UserRole::Admin => Self::Admin,
+        }
+    }
+}
+
+impl From<StoredUserRole> for UserRole {
+    fn from(role: StoredUserRole) -> Self {
+        match role {
+            StoredUserRole::User => Self::User,
+            StoredUserRole::Admin => Self::Admin,
+        }
+    }
+}
@@ -3,11 +3,12 @@ use lib::{
     infrastructure::persistence::entity::DomainTypeFromDb,
 };
 use model_mapper::Mapper;
+use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 
 use crate::features::user::domain::target_settings::UserTargetSettings;
 
-#[derive(Mapper, FromRow, Type, Debug)]
+#[derive(Mapper, FromRow, Type, Serialize, Deserialize, Clone, Debug)]
 #[mapper(ty = UserTargetSettings, from, into)]
 #[sqlx(type_name = "user_target_settings")]
 pub struct StoredUserTargetSettings {
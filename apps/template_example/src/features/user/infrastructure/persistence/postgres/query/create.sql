@@ -15,4 +15,5 @@ RETURNING
     email,
     password_hash,
     avatar_url,
+    role AS "role: StoredUserRole",
     target_settings AS "target_settings: StoredUserTargetSettings"
@@ -5,6 +5,7 @@ SELECT
     email,
     password_hash,
     avatar_url,
+    role AS "role: StoredUserRole",
     target_settings AS "target_settings: StoredUserTargetSettings"
 FROM users
 WHERE id = $1
@@ -0,0 +1,10 @@
+SELECT
+    id,
+    name,
+    surname,
+    email,
+    password_hash,
+    avatar_url,
+    role,
+    target_settings
+FROM users
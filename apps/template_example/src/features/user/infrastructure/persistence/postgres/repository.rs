@@ -3,8 +3,15 @@ use lib::{
     anyhow::Result,
     application::di::Has,
     async_trait,
-    domain::{DomainType, Id},
-    infrastructure::persistence::{HasPoolExt as _, SqlxPool},
+    domain::{
+        DomainType, Id,
+        pagination::{Page, PageRequest},
+    },
+    infrastructure::persistence::{
+        SqlxPool,
+        postgres::pagination::KeysetQuery,
+        transaction::HasSqlxConnectionExt as _,
+    },
     instrument_all, query_file_as,
     tap::{Conv as _, Pipe as _},
 };
@@ -15,7 +22,8 @@ use crate::{
         application::repository::UserRepositoryImpl,
         domain::{CreateUser, User},
         infrastructure::persistence::postgres::entity::{
-            StoredUser, target_settings::StoredUserTargetSettings,
+            StoredUser, role::StoredUserRole,
+            target_settings::StoredUserTargetSettings,
         },
     },
     shared::{
@@ -37,7 +45,7 @@ impl UserRepositoryImpl for PostgresRepositoryImpl {
     where
         App: Has<SqlxPool<Postgres>>,
     {
-        let mut connection = app.get_connection().await?;
+        let mut connection = app.acquire().await?;
 
         let id = id.value;
         let name = source.name.into_inner();
@@ -72,7 +80,7 @@ impl UserRepositoryImpl for PostgresRepositoryImpl {
     where
         App: Has<SqlxPool<Postgres>>,
     {
-        let mut connection = app.get_connection().await?;
+        let mut connection = app.acquire().await?;
 
         query_file_as!(StoredUser, "find_by_id.sql", id.value)
             .fetch_optional(&mut *connection)
@@ -88,7 +96,7 @@ impl UserRepositoryImpl for PostgresRepositoryImpl {
     where
         App: Has<SqlxPool<Postgres>>,
     {
-        let mut connection = app.get_connection().await?;
+        let mut connection = app.acquire().await?;
 
         query_file_as!(StoredUser, "find_by_email.sql", email.as_ref())
             .fetch_optional(&mut *connection)
@@ -96,4 +104,25 @@ impl UserRepositoryImpl for PostgresRepositoryImpl {
             .map(User::from)
             .pipe(Ok)
     }
+
+    async fn list_users<App>(
+        app: &App,
+        request: PageRequest,
+    ) -> Result<Page<User>>
+    where
+        App: Has<SqlxPool<Postgres>>,
+    {
+        let mut connection = app.acquire().await?;
+
+        let users = KeysetQuery::new(include_str!("query/list.sql"))
+            .page("id", &request)
+            .build_query_as::<StoredUser>()
+            .fetch_all(&mut *connection)
+            .await?
+            .into_iter()
+            .map(User::from)
+            .collect();
+
+        Page::from_keyset(&request, users, |user: &User| user.id).pipe(Ok)
+    }
 }
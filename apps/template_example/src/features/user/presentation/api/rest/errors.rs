@@ -12,7 +12,9 @@ impl From<UserUseCaseError> for ApiError {
             use StatusCode as C;
             use UserUseCaseError as E;
             match error {
-                E::Infrastructure(_) => Self::internal_server_error(error),
+                E::Infrastructure(_) | E::Transaction(_) | E::Outbox(_) => {
+                    Self::internal_server_error(error)
+                },
 
                 E::EmailAlreadyUsed(ref email) => (
                     C::CONFLICT,
@@ -45,6 +47,14 @@ impl From<UserUseCaseError> for ApiError {
                         "user_id": id.to_string()
                     }),
                 ),
+                E::Forbidden(forbidden) => (
+                    C::FORBIDDEN,
+                    "FORBIDDEN",
+                    error.to_string(),
+                    json!({
+                        "permission": forbidden.permission()
+                    }),
+                ),
             }
         };
 
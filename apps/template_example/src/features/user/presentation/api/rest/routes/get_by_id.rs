@@ -0,0 +1,64 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use lib::{
+    application::{authorization::Policy, di::Has},
+    domain::Id,
+    presentation::api::rest::{
+        authorization::Authorized, errors::JsonError,
+        response::ResponseExt as _,
+    },
+    tap::{Conv as _, Pipe as _},
+};
+use tracing::instrument;
+
+use super::profile::ReadUser;
+use crate::{
+    features::{
+        user::{
+            application::usecase::GetUserByIdUsecase, domain::User,
+            presentation::api::rest::dto::UserDto,
+        },
+        user_auth::presentation::api::rest::extractors::session::UserSession,
+    },
+    shared::presentation::api::rest::{
+        ApiError, B2C_TAG,
+        errors::BadRequestResponse,
+        extractors::{Json, Path},
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/users/{id}",
+    tag = B2C_TAG,
+    params(
+        ("id" = Uuid, Path),
+    ),
+    responses(
+        (status = OK, body = UserDto),
+        (status = UNAUTHORIZED, body = JsonError),
+        (status = FORBIDDEN, body = JsonError),
+        (status = NOT_FOUND, body = JsonError),
+        BadRequestResponse
+    ),
+)]
+#[instrument(skip(app))]
+pub async fn get_user<App>(
+    app: State<App>,
+    Authorized {
+        principal: user_session,
+        ..
+    }: Authorized<UserSession, ReadUser>,
+    Path(id): Path<Id<User>>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    App: GetUserByIdUsecase + Has<Policy>,
+{
+    app.get_user_by_id(&user_session.entity, id)
+        .await
+        .map_err(ApiError::from)?
+        .conv::<UserDto>()
+        .pipe(Json)
+        .into_response()
+        .with_status(StatusCode::OK)
+        .pipe(Ok)
+}
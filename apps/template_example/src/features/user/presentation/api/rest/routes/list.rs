@@ -0,0 +1,77 @@
+use axum::{
+    extract::{OriginalUri, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use lib::{
+    application::{authorization::Policy, di::Has},
+    presentation::api::rest::{
+        authorization::Authorized,
+        errors::JsonError,
+        pagination::{PageDto, PageQuery},
+        required_permission,
+        response::ResponseExt as _,
+        validation::parseable::Parseable as _,
+    },
+    tap::Pipe as _,
+};
+use tracing::instrument;
+
+use crate::{
+    features::{
+        user::{
+            application::{
+                authorization::LIST_USERS, usecase::ListUsersUsecase,
+            },
+            presentation::api::rest::dto::UserDto,
+        },
+        user_auth::presentation::api::rest::extractors::session::UserSession,
+    },
+    shared::presentation::api::rest::{
+        ApiError, B2C_TAG,
+        errors::{BadRequestResponse, ValidationFailedResponse},
+        extractors::{Json, Query},
+    },
+};
+
+required_permission!(pub ListUsers = LIST_USERS);
+
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = B2C_TAG,
+    params(PageQuery),
+    responses(
+        (status = OK, body = PageDto<UserDto>),
+        (status = UNAUTHORIZED, body = JsonError),
+        (status = FORBIDDEN, body = JsonError),
+        (status = TOO_MANY_REQUESTS, body = JsonError),
+        ValidationFailedResponse,
+        BadRequestResponse
+    ),
+)]
+#[instrument(skip(app))]
+pub async fn list_users<App>(
+    app: State<App>,
+    Authorized {
+        principal: user_session,
+        ..
+    }: Authorized<UserSession, ListUsers>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<PageQuery>,
+) -> Result<impl IntoResponse, ApiError>
+where
+    App: ListUsersUsecase + Has<Policy>,
+{
+    let request = query.parse()?;
+
+    app.list_users(&user_session.entity, request)
+        .await?
+        .pipe(|page| {
+            PageDto::<UserDto>::from_page(page, request.limit, uri.path())
+        })
+        .pipe(Json)
+        .into_response()
+        .with_status(StatusCode::OK)
+        .pipe(Ok)
+}
@@ -1,18 +1,31 @@
 use lib::{
+    application::{authorization::Policy, di::Has},
     presentation::api::rest::routes::Application,
     utoipa_axum::{router::OpenApiRouter, routes},
 };
 
-use crate::features::{
-    user::UserFeature,
-    user_auth::application::usecase::session::GetSessionFromTokenUsecase,
+use crate::{
+    features::{
+        user::UserFeature,
+        user_auth::application::usecase::session::GetSessionFromTokenUsecase,
+    },
+    shared::presentation::api::rest::secured,
 };
 
+pub mod get_by_id;
+pub mod list;
 pub mod profile;
 
 pub fn router<App>() -> OpenApiRouter<App>
 where
-    App: Application + UserFeature + GetSessionFromTokenUsecase,
+    App: Application + UserFeature + GetSessionFromTokenUsecase + Has<Policy>,
 {
-    OpenApiRouter::new().routes(routes!(profile::get_profile::<App>))
+    OpenApiRouter::new()
+        .routes(secured::<profile::ReadUser, _>(routes!(
+            profile::get_profile::<App>
+        )))
+        .routes(secured::<list::ListUsers, _>(routes!(list::list_users::<App>)))
+        .routes(secured::<profile::ReadUser, _>(routes!(
+            get_by_id::get_user::<App>
+        )))
 }
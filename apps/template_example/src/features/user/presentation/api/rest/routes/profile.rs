@@ -1,6 +1,10 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse};
 use lib::{
-    presentation::api::rest::{errors::JsonError, response::ResponseExt as _},
+    application::{authorization::Policy, di::Has},
+    presentation::api::rest::{
+        authorization::Authorized, errors::JsonError, required_permission,
+        response::ResponseExt as _,
+    },
     tap::{Conv as _, Pipe as _},
 };
 use tracing::instrument;
@@ -8,7 +12,9 @@ use tracing::instrument;
 use crate::{
     features::{
         user::{
-            application::usecase::GetUserByIdUsecase,
+            application::{
+                authorization::READ_USER, usecase::GetUserByIdUsecase,
+            },
             presentation::api::rest::dto::UserDto,
         },
         user_auth::presentation::api::rest::extractors::session::UserSession,
@@ -16,27 +22,30 @@ use crate::{
     shared::presentation::api::rest::{ApiError, B2C_TAG, extractors::Json},
 };
 
+required_permission!(pub ReadUser = READ_USER);
+
 #[utoipa::path(
     get,
     path = "/profile",
     tag = B2C_TAG,
-    security(
-        ("user" = []),
-    ),
     responses(
         (status = OK, body = UserDto),
         (status = UNAUTHORIZED, body = JsonError),
+        (status = FORBIDDEN, body = JsonError),
     ),
 )]
 #[instrument(skip(app))]
 pub async fn get_profile<App>(
     app: State<App>,
-    user_session: UserSession,
+    Authorized {
+        principal: user_session,
+        ..
+    }: Authorized<UserSession, ReadUser>,
 ) -> Result<impl IntoResponse, ApiError>
 where
-    App: GetUserByIdUsecase,
+    App: GetUserByIdUsecase + Has<Policy>,
 {
-    app.get_user_by_id(user_session.user_id)
+    app.get_user_by_id(&user_session.entity, user_session.user_id)
         .await
         .map_err(ApiError::from)?
         .conv::<UserDto>()
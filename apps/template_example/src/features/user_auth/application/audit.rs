@@ -0,0 +1,11 @@
+use lib::application::audit::Actor;
+
+use crate::features::user_auth::domain::session::entity::SessionEntity;
+
+impl From<SessionEntity> for Actor {
+    fn from(entity: SessionEntity) -> Self {
+        match entity {
+            SessionEntity::User(id, _) => Self::user(id),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use lib::application::authorization::{Principal, Role};
+
+use crate::features::{
+    user::domain::role::UserRole,
+    user_auth::domain::session::entity::SessionEntity,
+};
+
+pub const USER: Role = Role::new("user");
+pub const ADMIN: Role = Role::new("admin");
+
+impl Principal for SessionEntity {
+    fn roles(&self) -> &[Role] {
+        match self {
+            Self::User(_, UserRole::User) => &[USER],
+            Self::User(_, UserRole::Admin) => &[ADMIN],
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod audit;
+pub mod authorization;
 pub mod repository;
 pub mod service;
 pub mod usecase;
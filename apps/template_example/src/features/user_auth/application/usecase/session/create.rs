@@ -1,5 +1,12 @@
 use entrait::entrait;
-use lib::{redact::Secret, tap::Pipe as _};
+use lib::{
+    application::{
+        di::Has,
+        id::{HasIdGeneratorExt as _, SharedIdGenerator},
+    },
+    redact::Secret,
+    tap::Pipe as _,
+};
 use tracing::instrument;
 
 use super::SessionUseCaseResult;
@@ -17,12 +24,14 @@ async fn create_session<Deps>(
     entity: SessionEntity,
 ) -> SessionUseCaseResult<Secret<String>>
 where
-    Deps: SessionRepository + TokenService,
+    Deps: SessionRepository + TokenService + Has<SharedIdGenerator>,
 {
     let session = {
         use SessionEntity as SE;
         match entity {
-            SE::User(id) => Session::new_for_user(id),
+            SE::User(id, role) => {
+                Session::new_for_user(deps.generate_id(), id, role)
+            },
         }
     };
 
@@ -1,24 +1,24 @@
 use lib::{domain::Id, uuid::Uuid};
 
-use crate::features::user::domain::User;
+use crate::features::user::domain::{User, role::UserRole};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SessionEntity {
-    User(Id<User>),
+    User(Id<User>, UserRole),
 }
 
 impl From<SessionEntity> for Uuid {
     fn from(entity: SessionEntity) -> Self {
         use SessionEntity as SE;
         match entity {
-            SE::User(id) => id.value,
+            SE::User(id, _) => id.value,
         }
     }
 }
 
 impl From<User> for SessionEntity {
     fn from(user: User) -> Self {
-        Self::User(user.id)
+        Self::User(user.id, user.role)
     }
 }
 
@@ -26,7 +26,7 @@ impl SessionEntity {
     #[must_use]
     pub const fn as_tuple(&self) -> (&'static str, Uuid) {
         match self {
-            Self::User(id) => ("user", id.value),
+            Self::User(id, _) => ("user", id.value),
         }
     }
 }
@@ -1,32 +1,59 @@
+use std::time::Duration;
+
 use lib::domain::Id;
 
 use self::entity::SessionEntity;
 use crate::{
-    features::user::domain::User,
+    features::user::domain::{User, role::UserRole},
     shared::domain::{email::Email, password::Password},
 };
 
 pub mod entity;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Session {
     pub id: Id<Self>,
     pub entity: SessionEntity,
 }
 
 impl Session {
-    // one hour
-    pub const LIFETIME: usize = 60 * 60;
-
     #[must_use]
-    pub fn new_for_user(id: Id<User>) -> Self {
+    pub const fn new_for_user(
+        id: Id<Self>,
+        user_id: Id<User>,
+        role: UserRole,
+    ) -> Self {
         Self {
-            id: Id::generate(),
-            entity: SessionEntity::User(id),
+            id,
+            entity: SessionEntity::User(user_id, role),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionLifetime(Duration);
+
+impl SessionLifetime {
+    // one hour
+    pub const DEFAULT: Self = Self(Duration::from_secs(60 * 60));
+
+    #[must_use]
+    pub const fn new(lifetime: Duration) -> Self {
+        Self(lifetime)
+    }
+
+    #[must_use]
+    pub const fn as_secs(self) -> u64 {
+        self.0.as_secs()
+    }
+}
+
+impl Default for SessionLifetime {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 #[derive(Debug)]
 pub struct CreateSession {
     pub email: Email,
@@ -0,0 +1,57 @@
+use std::ops::Deref;
+
+use entrait::entrait;
+use lib::{
+    anyhow::Result,
+    application::di::Has,
+    async_trait,
+    domain::Id,
+    tap::Pipe as _,
+    testkit::table::InMemoryTable,
+};
+
+use crate::{
+    features::user_auth::{
+        application::repository::session::SessionRepositoryImpl,
+        domain::session::{Session, entity::SessionEntity},
+    },
+    shared::infrastructure::persistence::InMemoryRepositoryImpl,
+};
+
+#[derive(Clone, Default)]
+pub struct SessionTable(InMemoryTable<Session>);
+
+impl Deref for SessionTable {
+    type Target = InMemoryTable<Session>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn entity_key(entity: SessionEntity) -> Id<SessionEntity> {
+    Id::new(entity.into())
+}
+
+#[entrait(ref)]
+#[async_trait]
+impl SessionRepositoryImpl for InMemoryRepositoryImpl {
+    async fn save_session<App>(app: &App, source: Session) -> Result<Session>
+    where
+        App: Has<SessionTable>,
+    {
+        app.get_dependency().insert(entity_key(source.entity), source);
+
+        Ok(source)
+    }
+
+    async fn find_session_by_entity<App>(
+        app: &App,
+        entity: SessionEntity,
+    ) -> Result<Option<Session>>
+    where
+        App: Has<SessionTable>,
+    {
+        app.get_dependency().get(entity_key(entity)).pipe(Ok)
+    }
+}
@@ -1 +1,3 @@
+#[cfg(feature = "testkit")]
+pub mod in_memory;
 pub mod redis;
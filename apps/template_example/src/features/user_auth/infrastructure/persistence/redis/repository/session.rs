@@ -3,9 +3,14 @@ use std::{str::FromStr as _, sync::OnceLock};
 use entrait::entrait;
 use lib::{
     anyhow::{Context as _, Result},
-    application::di::Has,
+    application::{
+        di::Has,
+        retry::{RetryExt as _, RetryPolicy},
+    },
     async_trait,
-    infrastructure::persistence::{RedisPool, redis::Namespace},
+    infrastructure::persistence::{
+        HasPoolExt as _, RedisPool, redis::Namespace,
+    },
     instrument_all,
     tap::Pipe as _,
     uuid::Uuid,
@@ -15,7 +20,7 @@ use redis::AsyncTypedCommands as _;
 use crate::{
     features::user_auth::{
         application::repository::session::SessionRepositoryImpl,
-        domain::session::{Session, entity::SessionEntity},
+        domain::session::{Session, SessionLifetime, entity::SessionEntity},
     },
     shared::infrastructure::persistence::RedisRepositoryImpl,
 };
@@ -35,23 +40,27 @@ where
 impl SessionRepositoryImpl for RedisRepositoryImpl {
     async fn save_session<App>(app: &App, source: Session) -> Result<Session>
     where
-        App: Has<RedisPool> + HasSessionNamespace,
+        App: Has<RedisPool> + Has<SessionLifetime> + HasSessionNamespace,
     {
-        let mut connection = app.get_dependency().get().await?;
-
         let (entity_type, entity_id) = source.entity.as_tuple();
 
-        connection
-            .set_ex(
-                app.session_namespace()
-                    .nest(entity_type)
-                    .key(&entity_id.to_string()),
-                source.id.to_string(),
-                Session::LIFETIME
-                    .try_into()
-                    .context("while converting session lifetime")?,
-            )
-            .await?;
+        let key = &app
+            .session_namespace()
+            .nest(entity_type)
+            .key(&entity_id.to_string());
+        let value = &source.id.to_string();
+        let lifetime: &SessionLifetime = app.get_dependency();
+
+        (move || async move {
+            app.get_connection()
+                .await?
+                .set_ex(key, value, lifetime.as_secs())
+                .await?;
+
+            lib::anyhow::Ok(())
+        })
+        .retry_traced(RetryPolicy::new(), "redis.session.set_ex")
+        .await?;
 
         Ok(source)
     }
@@ -63,7 +72,7 @@ impl SessionRepositoryImpl for RedisRepositoryImpl {
     where
         App: Has<RedisPool> + HasSessionNamespace,
     {
-        let mut connection = app.get_dependency().get().await?;
+        let mut connection = app.get_connection().await?;
 
         let (entity_type, entity_id) = entity.as_tuple();
 
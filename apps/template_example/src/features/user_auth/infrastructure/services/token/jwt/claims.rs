@@ -1,28 +1,37 @@
-use lib::{chrono::Utc, uuid::Uuid};
+use lib::{
+    chrono::{DateTime, Utc},
+    uuid::Uuid,
+};
 use serde::{Deserialize, Serialize};
 
-use crate::features::user_auth::domain::session::{
-    Session, entity::SessionEntity,
+use crate::features::{
+    user::domain::role::UserRole,
+    user_auth::domain::session::{
+        Session, SessionLifetime, entity::SessionEntity,
+    },
 };
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum JWTRole {
     User,
+    Admin,
 }
 
 impl JWTRole {
     const fn from_session_entity(entity: &SessionEntity) -> (Self, Uuid) {
         use SessionEntity as E;
         match entity {
-            E::User(id) => (Self::User, id.value),
+            E::User(id, UserRole::User) => (Self::User, id.value),
+            E::User(id, UserRole::Admin) => (Self::Admin, id.value),
         }
     }
 
     fn into_session_entity(self, id: Uuid) -> SessionEntity {
         use SessionEntity as E;
         match self {
-            Self::User => E::User(id.into()),
+            Self::User => E::User(id.into(), UserRole::User),
+            Self::Admin => E::User(id.into(), UserRole::Admin),
         }
     }
 }
@@ -36,19 +45,35 @@ pub struct Claims {
     jti: Uuid,
 }
 
-impl From<Session> for Claims {
-    fn from(session: Session) -> Self {
-        let current_time =
-            usize::try_from(Utc::now().timestamp()).unwrap_or(usize::MAX);
+impl Claims {
+    #[must_use]
+    pub fn new(
+        session: Session,
+        issued_at: DateTime<Utc>,
+        lifetime: SessionLifetime,
+    ) -> Self {
+        let current_time = timestamp(issued_at);
+        let lifetime =
+            usize::try_from(lifetime.as_secs()).unwrap_or(usize::MAX);
         let (role, sub) = JWTRole::from_session_entity(&session.entity);
         Self {
-            exp: current_time.saturating_add(Session::LIFETIME),
+            exp: current_time.saturating_add(lifetime),
             iat: current_time,
             sub,
             role,
             jti: session.id.value,
         }
     }
+
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>, leeway: u64) -> bool {
+        let leeway = usize::try_from(leeway).unwrap_or(usize::MAX);
+        self.exp.saturating_add(leeway) < timestamp(now)
+    }
+}
+
+fn timestamp(at: DateTime<Utc>) -> usize {
+    usize::try_from(at.timestamp()).unwrap_or(usize::MAX)
 }
 
 impl From<Claims> for Session {
@@ -2,8 +2,11 @@ use entrait::entrait;
 use jsonwebtoken::{Algorithm, Header, Validation, decode, encode};
 pub use jsonwebtoken::{DecodingKey, EncodingKey};
 use lib::{
-    anyhow::{Context as _, Result},
-    application::di::Has,
+    anyhow::{Context as _, Result, ensure},
+    application::{
+        clock::{HasClockExt as _, SharedClock},
+        di::Has,
+    },
     instrument_all,
     redact::Secret,
     tap::{Conv as _, Pipe as _},
@@ -11,7 +14,8 @@ use lib::{
 
 use self::claims::Claims;
 use crate::features::user_auth::{
-    application::service::token::TokenServiceImpl, domain::session::Session,
+    application::service::token::TokenServiceImpl,
+    domain::session::{Session, SessionLifetime},
 };
 
 mod claims;
@@ -30,12 +34,15 @@ impl TokenServiceImpl for JwtService {
         session: Session,
     ) -> Result<Secret<String>>
     where
-        App: Has<Self>,
+        App: Has<Self> + Has<SharedClock> + Has<SessionLifetime>,
     {
+        let service: &Self = app.get_dependency();
+        let lifetime: &SessionLifetime = app.get_dependency();
+
         encode(
             &Header::new(Algorithm::HS256),
-            &Claims::from(session),
-            &app.get_dependency().encoding_key,
+            &Claims::new(session, app.now(), *lifetime),
+            &service.encoding_key,
         )
         .map(Secret::new)
         .context("while encoding jwt")
@@ -43,17 +50,27 @@ impl TokenServiceImpl for JwtService {
 
     fn parse_token<App>(app: &App, token: Secret<&str>) -> Result<Session>
     where
-        App: Has<Self>,
+        App: Has<Self> + Has<SharedClock>,
     {
-        decode::<Claims>(
+        let service: &Self = app.get_dependency();
+
+        let mut validation = Validation::default();
+        validation.validate_exp = false;
+
+        let claims = decode::<Claims>(
             token.expose_secret(),
-            &app.get_dependency().decoding_key,
-            &Validation::default(),
+            &service.decoding_key,
+            &validation,
         )
         .context("while decoding jwt")?
-        .claims
-        .conv::<Session>()
-        .pipe(Ok)
+        .claims;
+
+        ensure!(
+            !claims.is_expired(app.now(), validation.leeway),
+            "jwt has expired"
+        );
+
+        claims.conv::<Session>().pipe(Ok)
     }
 }
 
@@ -5,7 +5,16 @@ use axum_extra::{
     TypedHeader,
     headers::{Authorization, authorization::Bearer},
 };
-use lib::{domain::Id, redact::Secret, tap::Pipe as _};
+use lib::{
+    application::authorization::{Principal, Role},
+    domain::Id,
+    presentation::api::rest::{
+        feature_flag::FeatureFlagSubject, rate_limit::RateLimitSubject,
+    },
+    redact::Secret,
+    tap::Pipe as _,
+    uuid::Uuid,
+};
 
 use crate::{
     features::{
@@ -23,6 +32,13 @@ use crate::{
 pub struct UserSession {
     pub id: Id<Session>,
     pub user_id: Id<User>,
+    pub entity: SessionEntity,
+}
+
+impl Principal for UserSession {
+    fn roles(&self) -> &[Role] {
+        self.entity.roles()
+    }
 }
 
 impl<App> FromRequestParts<App> for UserSession
@@ -50,9 +66,10 @@ where
             reason = "other session entities may be added in the future"
         )]
         match session.entity {
-            SessionEntity::User(user_id) => Self {
+            SessionEntity::User(user_id, _) => Self {
                 id: session.id,
                 user_id,
+                entity: session.entity,
             }
             .pipe(Ok),
             _ => AuthError::InvalidToken
@@ -61,3 +78,15 @@ where
         }
     }
 }
+
+impl FeatureFlagSubject for UserSession {
+    fn feature_flag_subject(&self) -> Uuid {
+        self.user_id.value
+    }
+}
+
+impl RateLimitSubject for UserSession {
+    fn rate_limit_key(&self) -> String {
+        self.user_id.value.to_string()
+    }
+}
@@ -29,6 +29,15 @@ use crate::{
     path = "/sign-in",
     tag = B2C_TAG,
     request_body = CreateSessionDto,
+    params(
+        (
+            "idempotency-key" = Option<String>,
+            Header,
+            description = "Ключ идемпотентности для безопасного повтора запроса. \
+                           Ответ с токеном не сохраняется: повтор возвращает \
+                           тот же статус без тела."
+        ),
+    ),
     responses(
         (
             status = OK,
@@ -38,6 +47,21 @@ use crate::{
             status = UNAUTHORIZED,
             body = JsonError
         ),
+        (
+            status = CONFLICT,
+            body = JsonError,
+            description = "Ключ идемпотентности уже использован для другого \
+                           запроса или запрос с этим ключом ещё выполняется."
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            body = JsonError,
+            description = "Некорректный ключ идемпотентности."
+        ),
+        (
+            status = TOO_MANY_REQUESTS,
+            body = JsonError
+        ),
         BadRequestResponse
     ),
 )]
@@ -60,5 +84,6 @@ where
         .pipe(Json)
         .into_response()
         .with_status(StatusCode::OK)
+        .no_store()
         .pipe(Ok)
 }
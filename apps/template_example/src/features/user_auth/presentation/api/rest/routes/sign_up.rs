@@ -32,6 +32,16 @@ use crate::{
     path = "/sign-up",
     tag = B2C_TAG,
     request_body = CreateUserDto,
+    params(
+        (
+            "idempotency-key" = Option<String>,
+            Header,
+            description = "Ключ идемпотентности для безопасного повтора запроса. \
+                           Некорректный ключ отклоняется с кодом 422. \
+                           Ответ с токеном не сохраняется: повтор возвращает \
+                           тот же статус без тела."
+        ),
+    ),
     responses(
         (
             status = OK,
@@ -41,7 +51,14 @@ use crate::{
         (
             status = CONFLICT,
             body = JsonError,
-            description = "Такой email уже зарегистрирован в системе"
+            description = "Такой email уже зарегистрирован в системе, \
+                           ключ идемпотентности уже использован для другого \
+                           запроса или запрос с этим ключом ещё выполняется."
+        ),
+        (
+            status = TOO_MANY_REQUESTS,
+            body = JsonError,
+            description = "Превышен лимит запросов, повторите позже."
         ),
         ValidationFailedResponse,
         BadRequestResponse
@@ -66,5 +83,6 @@ where
         .pipe(Json)
         .into_response()
         .with_status(StatusCode::OK)
+        .no_store()
         .pipe(Ok)
 }
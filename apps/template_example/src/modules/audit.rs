@@ -0,0 +1,29 @@
+use lib::{
+    application::{
+        audit::AuditLog, clock::SharedClock, id::SharedIdGenerator, impl_has,
+    },
+    infrastructure::persistence::postgres::audit::PostgresAuditStore,
+    presentation::api::rest::audit::request_context,
+    tap::Pipe as _,
+};
+
+use super::{Modules, repositories::RepositoriesModule};
+
+impl Modules {
+    pub(super) fn setup_audit(
+        repositories: &RepositoriesModule,
+        clock: &SharedClock,
+        id_generator: &SharedIdGenerator,
+    ) -> AuditLog {
+        PostgresAuditStore::new(repositories.postgres().clone())
+            .pipe(AuditLog::new)
+            .context(request_context)
+            .clock(clock.clone())
+            .id_generator(id_generator.clone())
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    AuditLog: |s| &s.audit,
+}
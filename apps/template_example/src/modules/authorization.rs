@@ -0,0 +1,31 @@
+use lib::application::{
+    authorization::{Permission, Policy},
+    impl_has,
+};
+
+use super::Modules;
+use crate::features::{
+    audit::application::authorization::READ_AUDIT_LOG,
+    user::application::authorization::{LIST_USERS, READ_USER},
+    user_auth::application::authorization::{ADMIN, USER},
+};
+
+pub const READ_HEALTH_HISTORY: Permission = Permission::new("health:read");
+
+impl Modules {
+    pub(super) fn setup_policy() -> Policy {
+        Policy::builder()
+            .grant_own(USER, [READ_USER])
+            .inherit(ADMIN, USER)
+            .grant(
+                ADMIN,
+                [READ_USER, LIST_USERS, READ_AUDIT_LOG, READ_HEALTH_HISTORY],
+            )
+            .build()
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    Policy: |s| &s.policy,
+}
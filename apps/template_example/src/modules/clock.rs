@@ -0,0 +1,17 @@
+use lib::application::{
+    clock::{SharedClock, SystemClock},
+    impl_has,
+};
+
+use super::Modules;
+
+impl Modules {
+    pub(super) fn setup_clock() -> SharedClock {
+        SystemClock::shared()
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    SharedClock: |s| &s.clock,
+}
@@ -1,6 +1,10 @@
 use fromenv::FromEnv;
+use lib::bootstrap::config::{Validate, Validator};
 
-use super::{repositories::RepositoriesConfig, services::ServicesConfig};
+use super::{
+    feature_flags::FeatureFlagsConfig, repositories::RepositoriesConfig,
+    services::ServicesConfig, session::SessionConfig,
+};
 
 #[derive(FromEnv)]
 pub struct ModulesConfig {
@@ -8,4 +12,18 @@ pub struct ModulesConfig {
     pub repositories: RepositoriesConfig,
     #[env(nested)]
     pub services: ServicesConfig,
+    #[env(nested)]
+    pub feature_flags: FeatureFlagsConfig,
+    #[env(nested)]
+    pub session: SessionConfig,
+}
+
+impl Validate for ModulesConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .nested(&self.repositories)
+            .nested(&self.services)
+            .nested(&self.feature_flags)
+            .nested(&self.session);
+    }
 }
@@ -0,0 +1,17 @@
+use lib::application::{event::EventBus, impl_has};
+
+use super::Modules;
+use crate::features::user::application::event::log_user_created;
+
+impl Modules {
+    pub(super) fn setup_events() -> EventBus {
+        EventBus::builder()
+            .subscribe("user.log_created", log_user_created)
+            .build()
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    EventBus: |s| &s.events,
+}
@@ -0,0 +1,56 @@
+use fromenv::FromEnv;
+use lib::{
+    application::{
+        feature_flag::{
+            EnvFlagProvider, FeatureFlags, FileFlagProvider, FlagDefinition,
+        },
+        impl_has,
+    },
+    bootstrap::config::{Validate, Validator},
+    infrastructure::persistence::feature_flag::RedisFlagProvider,
+};
+
+use super::{Modules, ModulesConfig, repositories::RepositoriesModule};
+use crate::features::user::application::feature_flag::USER_LISTING;
+
+#[derive(FromEnv)]
+#[env(prefix = "FLAGS_")]
+pub struct FeatureFlagsConfig {
+    pub file: Option<String>,
+}
+
+impl Validate for FeatureFlagsConfig {
+    fn validate(&self, validator: &mut Validator) {
+        if let Some(file) = &self.file {
+            validator.non_empty("FLAGS_FILE", file);
+        }
+    }
+}
+
+impl Modules {
+    pub(super) fn setup_feature_flags(
+        config: &ModulesConfig,
+        repositories: &RepositoriesModule,
+    ) -> FeatureFlags {
+        let builder = FeatureFlags::builder()
+            .flag(USER_LISTING, FlagDefinition::boolean(true))
+            .provider(EnvFlagProvider::default());
+
+        let builder = match &config.feature_flags.file {
+            Some(path) => builder.provider(FileFlagProvider::new(path)),
+            None => builder,
+        };
+
+        builder
+            .provider(RedisFlagProvider::new(
+                repositories.redis().clone(),
+                RepositoriesModule::namespace(&config.repositories),
+            ))
+            .build()
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    FeatureFlags: |s| &s.feature_flags,
+}
@@ -0,0 +1,17 @@
+use lib::application::{
+    id::{SharedIdGenerator, UuidV7Generator},
+    impl_has,
+};
+
+use super::Modules;
+
+impl Modules {
+    pub(super) fn setup_id_generator() -> SharedIdGenerator {
+        UuidV7Generator::shared()
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    SharedIdGenerator: |s| &s.id_generator,
+}
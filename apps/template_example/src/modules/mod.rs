@@ -1,34 +1,96 @@
+use std::time::Duration;
+
 use lib::{
     application::{
+        audit::AuditLog,
+        authorization::Policy,
+        clock::SharedClock,
         di::Has as _,
-        health::{HealthCheck as _, Readiness, ReadinessReport},
+        event::EventBus,
+        feature_flag::FeatureFlags,
+        health::{
+            CheckOptions, HealthCheck as _, HistoryEntry, Readiness,
+            ReadinessCache, ReadinessReport,
+        },
+        id::SharedIdGenerator,
+        outbox::SharedOutbox,
+        shutdown::ShutdownHooks,
     },
     infrastructure::persistence::{RedisPool, SqlxPool},
     mobc_sqlx::sqlx::Postgres,
-    presentation::api::rest::health::ReadinessCheck,
+    presentation::api::rest::health::{HealthHistorySource, ReadinessCheck},
 };
 
-pub use self::config::ModulesConfig;
-use self::{repositories::RepositoriesModule, services::ServicesModule};
+pub use self::{authorization::READ_HEALTH_HISTORY, config::ModulesConfig};
+use self::{
+    repositories::{RepositoriesError, RepositoriesModule},
+    services::ServicesModule,
+};
+use crate::features::user_auth::domain::session::SessionLifetime;
 
+mod audit;
+mod authorization;
+mod clock;
 mod config;
+mod events;
+mod feature_flags;
+mod id;
+mod outbox;
 mod repositories;
 mod services;
+mod session;
+mod shutdown;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ModulesError {
+    #[error(transparent)]
+    Repositories(#[from] RepositoriesError),
+}
 
 #[derive(Clone)]
 pub struct Modules {
     config: &'static ModulesConfig,
+    clock: SharedClock,
+    id_generator: SharedIdGenerator,
     repositories: RepositoriesModule,
     services: ServicesModule,
+    session_lifetime: SessionLifetime,
+    events: EventBus,
+    policy: Policy,
+    feature_flags: FeatureFlags,
+    audit: AuditLog,
+    outbox: SharedOutbox,
+    readiness: ReadinessCache,
+    shutdown_hooks: ShutdownHooks,
 }
 
 impl Modules {
-    pub async fn init(config: &'static ModulesConfig) -> Self {
-        Self {
+    pub async fn init(
+        config: &'static ModulesConfig,
+    ) -> Result<Self, ModulesError> {
+        let repositories =
+            RepositoriesModule::new(&config.repositories).await?;
+        let clock = Self::setup_clock();
+        let events = Self::setup_events();
+        let id_generator = Self::setup_id_generator();
+
+        Ok(Self {
             config,
-            repositories: RepositoriesModule::new(&config.repositories).await,
+            feature_flags: Self::setup_feature_flags(config, &repositories),
+            audit: Self::setup_audit(&repositories, &clock, &id_generator),
+            outbox: Self::setup_outbox(&repositories),
+            shutdown_hooks: Self::setup_shutdown_hooks(&repositories, &events),
+            repositories,
             services: ServicesModule::new(&config.services),
-        }
+            session_lifetime: Self::setup_session_lifetime(config),
+            events,
+            policy: Self::setup_policy(),
+            readiness: ReadinessCache::default().clock(clock.clone()),
+            clock,
+            id_generator,
+        })
     }
 }
 
@@ -37,10 +99,25 @@ impl ReadinessCheck for Modules {
         let postgres: &SqlxPool<Postgres> = self.get_dependency();
         let redis: &RedisPool = self.get_dependency();
 
-        Readiness::new()
-            .check("postgres", postgres.health_check())
-            .check("redis", redis.health_check())
-            .run()
+        self.readiness
+            .get_or_probe(|| {
+                Readiness::new()
+                    .clock(self.clock.clone())
+                    .check("postgres", postgres.health_check())
+                    .check_with(
+                        "redis",
+                        CheckOptions::non_critical()
+                            .timeout(Duration::from_secs(1)),
+                        redis.health_check(),
+                    )
+                    .run()
+            })
             .await
     }
 }
+
+impl HealthHistorySource for Modules {
+    fn health_history(&self) -> Vec<HistoryEntry> {
+        self.readiness.history().snapshot()
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use lib::{
+    application::{impl_has, outbox::SharedOutbox},
+    infrastructure::persistence::postgres::outbox::PostgresOutbox,
+};
+
+use super::{Modules, repositories::RepositoriesModule};
+
+impl Modules {
+    pub(super) fn setup_outbox(
+        repositories: &RepositoriesModule,
+    ) -> SharedOutbox {
+        Arc::new(PostgresOutbox::new(repositories.postgres().clone()))
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    SharedOutbox: |s| &s.outbox,
+}
@@ -1,4 +1,8 @@
 use fromenv::FromEnv;
+use lib::bootstrap::{
+    config::{Validate, Validator},
+    startup::StartupConfig,
+};
 
 use super::{postgres::PostgresConfig, redis::RedisConfig};
 
@@ -8,4 +12,15 @@ pub struct RepositoriesConfig {
     pub postgres: PostgresConfig,
     #[env(nested)]
     pub redis: RedisConfig,
+    #[env(nested)]
+    pub startup: StartupConfig,
+}
+
+impl Validate for RepositoriesConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .nested(&self.postgres)
+            .nested(&self.redis)
+            .nested(&self.startup);
+    }
 }
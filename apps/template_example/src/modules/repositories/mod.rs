@@ -1,13 +1,21 @@
-use std::sync::OnceLock;
+use std::{sync::OnceLock, time::Duration};
 
 use lib::{
-    application::impl_has,
-    bootstrap::impl_repositories,
-    infrastructure::persistence::{RedisPool, SqlxPool, redis::Namespace},
+    application::{
+        cache::CachePolicy, impl_has, transaction::HasTransactionManager,
+    },
+    bootstrap::{
+        impl_repositories,
+        startup::{DependenciesUnavailable, WaitForDependencies},
+    },
+    infrastructure::persistence::{
+        RedisPool, SqlxPool, mobc_sqlx::MigrationError, redis::Namespace,
+    },
     mobc_sqlx::sqlx::Postgres,
 };
 
 pub use self::config::RepositoriesConfig;
+use self::redis::RedisClientError;
 use super::Modules;
 use crate::{
     features::{
@@ -15,7 +23,8 @@ use crate::{
         user_auth::application::repository::session::SessionRepositoryImpl,
     },
     shared::infrastructure::persistence::{
-        PostgresRepositoryImpl, RedisRepositoryImpl,
+        CachedPostgresRepositoryImpl, PostgresTransactionManager,
+        RedisRepositoryImpl,
     },
 };
 
@@ -23,6 +32,18 @@ mod config;
 mod postgres;
 mod redis;
 
+#[derive(thiserror::Error, Debug)]
+pub enum RepositoriesError {
+    #[error(transparent)]
+    Redis(#[from] RedisClientError),
+
+    #[error(transparent)]
+    Unavailable(#[from] DependenciesUnavailable),
+
+    #[error(transparent)]
+    Migration(#[from] MigrationError),
+}
+
 #[derive(Clone)]
 pub struct RepositoriesModule {
     postgres: SqlxPool<Postgres>,
@@ -30,11 +51,42 @@ pub struct RepositoriesModule {
 }
 
 impl RepositoriesModule {
-    pub(crate) async fn new(config: &RepositoriesConfig) -> Self {
-        Self {
-            postgres: Self::setup_postgres(&config.postgres).await,
-            redis: Self::setup_redis(&config.redis),
+    pub(crate) async fn new(
+        config: &RepositoriesConfig,
+    ) -> Result<Self, RepositoriesError> {
+        let postgres = Self::setup_postgres(&config.postgres);
+        let redis = Self::setup_redis(&config.redis)?;
+
+        WaitForDependencies::from(&config.startup)
+            .probe("postgres", &postgres)
+            .probe("redis", &redis)
+            .wait()
+            .await?;
+
+        if config.postgres.run_migrator {
+            Self::migrate_postgres(&postgres).await?;
         }
+
+        Ok(Self {
+            postgres,
+            redis,
+        })
+    }
+
+    pub(super) const fn postgres(&self) -> &SqlxPool<Postgres> {
+        &self.postgres
+    }
+
+    pub(super) const fn redis(&self) -> &RedisPool {
+        &self.redis
+    }
+
+    pub(super) fn namespace(config: &RepositoriesConfig) -> &'static Namespace {
+        static NAMESPACE: OnceLock<Namespace> = OnceLock::new();
+        NAMESPACE.get_or_init(|| {
+            Namespace::new(&config.redis.service_namespace)
+                .nest(&config.redis.service_name)
+        })
     }
 }
 
@@ -42,17 +94,23 @@ impl_has! {
     struct: Modules,
     SqlxPool<Postgres>: |s| &s.repositories.postgres,
     RedisPool: |s| &s.repositories.redis,
-    Namespace: |s| {
-        static NAMESPACE: OnceLock<Namespace> = OnceLock::new();
-        NAMESPACE.get_or_init(|| {
-            Namespace::new(&s.config.repositories.redis.service_namespace)
-                .nest(&s.config.repositories.redis.service_name)
-        })
+    Namespace: |s| RepositoriesModule::namespace(&s.config.repositories),
+}
+
+impl HasTransactionManager for Modules {
+    type TransactionManager = PostgresTransactionManager;
+
+    fn transaction_manager(&self) -> &Self::TransactionManager {
+        &self.repositories.postgres
     }
 }
 
 impl_repositories! {
     struct: Modules,
-    DelegateUserRepository: PostgresRepositoryImpl,
+    DelegateUserRepository: CachedPostgresRepositoryImpl => |s| {
+        let redis = &s.config.repositories.redis;
+        CachePolicy::new("user", Duration::from_secs(redis.cache_ttl_secs))
+            .negative_ttl(Duration::from_secs(redis.cache_negative_ttl_secs))
+    },
     SessionRepositoryImpl: |_cfg| &RedisRepositoryImpl,
 }
@@ -1,4 +1,5 @@
 use fromenv::FromEnv;
+use lib::bootstrap::config::{SecretString, Validate, Validator};
 use sqlx::postgres::PgConnectOptions;
 
 #[derive(FromEnv)]
@@ -7,18 +8,28 @@ pub struct PostgresConfig {
     #[env(default = "true")]
     pub run_migrator: bool,
     pub user: String,
-    pub password: String,
+    pub password: SecretString,
     pub host: String,
     #[env(default = "5432")]
     pub port: u16,
     pub database: String,
 }
 
+impl Validate for PostgresConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .non_empty("POSTGRES_USER", &self.user)
+            .non_empty("POSTGRES_HOST", &self.host)
+            .port("POSTGRES_PORT", self.port)
+            .non_empty("POSTGRES_DATABASE", &self.database);
+    }
+}
+
 impl From<&PostgresConfig> for PgConnectOptions {
     fn from(config: &PostgresConfig) -> Self {
         Self::new()
             .username(&config.user)
-            .password(&config.password)
+            .password(config.password.expose_secret())
             .host(&config.host)
             .port(config.port)
             .database(&config.database)
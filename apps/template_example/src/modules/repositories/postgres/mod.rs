@@ -1,9 +1,17 @@
 use lib::{
-    infrastructure::persistence::{SqlxPool, mobc_sqlx::migrate_all},
+    application::circuit_breaker::CircuitBreaker,
+    infrastructure::persistence::{
+        SqlxPool,
+        mobc_sqlx::{MigrationError, migrate_all},
+        postgres::{
+            audit::AUDIT_LOG_MIGRATOR, outbox::OUTBOX_MIGRATOR,
+            queue::JOB_QUEUE_MIGRATOR,
+        },
+    },
     mobc_sqlx::SqlxConnectionManager,
     tap::Pipe as _,
 };
-use sqlx::{Postgres, migrate::Migrator, postgres::PgConnectOptions};
+use sqlx::{Postgres, postgres::PgConnectOptions};
 
 pub(super) use self::config::PostgresConfig;
 use super::RepositoriesModule;
@@ -11,20 +19,28 @@ use crate::features::user::infrastructure::persistence::postgres::USER_POSTGRES_
 
 mod config;
 
-static POSTGRES_MIGRATORS: &[&Migrator] = &[&USER_POSTGRES_MIGRATOR];
-
 impl RepositoriesModule {
-    pub(super) async fn setup_postgres(
+    pub(super) fn setup_postgres(
         config: &PostgresConfig,
     ) -> SqlxPool<Postgres> {
-        let postgres = PgConnectOptions::from(config)
+        PgConnectOptions::from(config)
             .pipe(SqlxConnectionManager::new)
-            .pipe(SqlxPool::new);
-
-        if config.run_migrator {
-            migrate_all(&postgres, POSTGRES_MIGRATORS).await;
-        }
+            .pipe(SqlxPool::new)
+            .with_circuit_breaker(CircuitBreaker::new("postgres"))
+    }
 
-        postgres
+    pub(super) async fn migrate_postgres(
+        postgres: &SqlxPool<Postgres>,
+    ) -> Result<(), MigrationError> {
+        migrate_all(
+            postgres,
+            &[
+                &USER_POSTGRES_MIGRATOR,
+                &OUTBOX_MIGRATOR,
+                &JOB_QUEUE_MIGRATOR,
+                &AUDIT_LOG_MIGRATOR,
+            ],
+        )
+        .await
     }
 }
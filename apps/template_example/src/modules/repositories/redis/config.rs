@@ -1,11 +1,7 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
-use std::fmt::Write as _;
+use std::fmt::{self, Write as _};
 
 use fromenv::FromEnv;
+use lib::bootstrap::config::{SecretString, Validate, Validator};
 
 #[derive(FromEnv)]
 #[env(prefix = "REDIS_")]
@@ -13,24 +9,49 @@ pub struct RedisConfig {
     pub host: String,
     pub port: Option<u16>,
     pub user: Option<String>,
-    pub password: Option<String>,
+    pub password: Option<SecretString>,
     pub database: Option<String>,
     #[env(default = "template_example")]
     pub service_namespace: String,
     #[env(default = "monolyth")]
     pub service_name: String,
+    #[env(default = "300")]
+    pub cache_ttl_secs: u64,
+    #[env(default = "30")]
+    pub cache_negative_ttl_secs: u64,
+}
+
+impl Validate for RedisConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.non_empty("REDIS_HOST", &self.host);
+
+        if let Some(port) = self.port {
+            validator.port("REDIS_PORT", port);
+        }
+    }
 }
 
-impl From<&RedisConfig> for redis::Client {
-    fn from(config: &RedisConfig) -> Self {
-        let url = try {
+#[derive(thiserror::Error, Debug)]
+pub enum RedisClientError {
+    #[error("failed to format the redis url")]
+    Url(#[from] fmt::Error),
+
+    #[error("failed to open the redis client: {0}")]
+    Open(#[from] redis::RedisError),
+}
+
+impl TryFrom<&RedisConfig> for redis::Client {
+    type Error = RedisClientError;
+
+    fn try_from(config: &RedisConfig) -> Result<Self, Self::Error> {
+        let url: Result<String, fmt::Error> = try {
             let mut url = "redis://".to_string();
 
             if let Some(username) = &config.user {
                 write!(url, "{username}")?;
 
                 if let Some(password) = &config.password {
-                    write!(url, ":{password}")?;
+                    write!(url, ":{}", password.expose_secret())?;
                 }
 
                 write!(url, "@")?;
@@ -47,9 +68,8 @@ impl From<&RedisConfig> for redis::Client {
             }
 
             url
-        }
-        .expect("url formatting should finish successfully");
+        };
 
-        Self::open(url).expect("redis client should open successfully")
+        Ok(Self::open(url?)?)
     }
 }
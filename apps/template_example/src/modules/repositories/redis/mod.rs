@@ -1,17 +1,22 @@
 use lib::{
+    application::circuit_breaker::CircuitBreaker,
     infrastructure::persistence::RedisPool, mobc_redis::RedisConnectionManager,
     tap::Pipe as _,
 };
 
-pub(super) use self::config::RedisConfig;
+pub(super) use self::config::{RedisClientError, RedisConfig};
 use super::RepositoriesModule;
 
 mod config;
 
 impl RepositoriesModule {
-    pub(super) fn setup_redis(config: &RedisConfig) -> RedisPool {
-        redis::Client::from(config)
+    pub(super) fn setup_redis(
+        config: &RedisConfig,
+    ) -> Result<RedisPool, RedisClientError> {
+        redis::Client::try_from(config)?
             .pipe(RedisConnectionManager::new)
             .pipe(RedisPool::new)
+            .with_circuit_breaker(CircuitBreaker::new("redis"))
+            .pipe(Ok)
     }
 }
@@ -1,24 +1,43 @@
 use fromenv::FromEnv;
+use lib::bootstrap::config::{SecretString, Validate, Validator};
 
 use crate::features::user_auth::infrastructure::services::token::jwt::{
     DecodingKey, EncodingKey, JwtService,
 };
 
+pub const JWT_SECRET_MIN_LEN: usize = 32;
+
 #[derive(FromEnv)]
 pub struct ServicesConfig {
     #[env(nested)]
     pub jwt: JwtConfig,
 }
 
+impl Validate for ServicesConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.nested(&self.jwt);
+    }
+}
+
 #[derive(FromEnv)]
 #[env(prefix = "JWT_")]
 pub struct JwtConfig {
-    pub secret: String,
+    pub secret: SecretString,
+}
+
+impl Validate for JwtConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.secret(
+            "JWT_SECRET",
+            self.secret.expose_secret(),
+            JWT_SECRET_MIN_LEN,
+        );
+    }
 }
 
 impl From<&JwtConfig> for JwtService {
     fn from(config: &JwtConfig) -> Self {
-        let secret = config.secret.as_bytes();
+        let secret = config.secret.expose_secret().as_bytes();
         Self::new(
             EncodingKey::from_secret(secret),
             DecodingKey::from_secret(secret),
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use fromenv::FromEnv;
+use lib::{
+    application::impl_has,
+    bootstrap::config::{Validate, Validator},
+};
+
+use super::{Modules, ModulesConfig};
+use crate::features::user_auth::domain::session::SessionLifetime;
+
+#[derive(FromEnv)]
+#[env(prefix = "SESSION_")]
+pub struct SessionConfig {
+    #[env(default = "3600")]
+    pub lifetime_secs: u64,
+}
+
+impl Validate for SessionConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator.positive("SESSION_LIFETIME_SECS", self.lifetime_secs);
+    }
+}
+
+impl Modules {
+    pub(super) const fn setup_session_lifetime(
+        config: &ModulesConfig,
+    ) -> SessionLifetime {
+        SessionLifetime::new(Duration::from_secs(config.session.lifetime_secs))
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    SessionLifetime: |s| &s.session_lifetime,
+}
@@ -0,0 +1,20 @@
+use lib::application::{event::EventBus, impl_has, shutdown::ShutdownHooks};
+
+use super::{Modules, repositories::RepositoriesModule};
+
+impl Modules {
+    pub(super) fn setup_shutdown_hooks(
+        repositories: &RepositoriesModule,
+        events: &EventBus,
+    ) -> ShutdownHooks {
+        ShutdownHooks::new()
+            .hook("events", events.clone())
+            .hook("postgres", repositories.postgres().clone())
+            .hook("redis", repositories.redis().clone())
+    }
+}
+
+impl_has! {
+    struct: Modules,
+    ShutdownHooks: |s| &s.shutdown_hooks,
+}
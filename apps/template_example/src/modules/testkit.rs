@@ -0,0 +1,86 @@
+use lib::{
+    application::impl_has,
+    bootstrap::{impl_repositories, impl_services},
+    testkit::container::{TestContainer, TestContainerBuilder},
+};
+
+use super::Modules;
+use crate::{
+    features::{
+        user::{
+            application::repository::DelegateUserRepository,
+            infrastructure::persistence::in_memory::UserTable,
+        },
+        user_auth::{
+            application::{
+                repository::session::SessionRepositoryImpl,
+                service::{
+                    secret_hasher::DelegateSecretHasherService,
+                    token::DelegateTokenService,
+                },
+            },
+            domain::session::SessionLifetime,
+            infrastructure::{
+                persistence::in_memory::SessionTable,
+                services::{
+                    hasher::argon2::Argon2Service,
+                    token::jwt::{DecodingKey, EncodingKey, JwtService},
+                },
+            },
+        },
+    },
+    shared::infrastructure::persistence::InMemoryRepositoryImpl,
+};
+
+pub const TEST_JWT_SECRET: &[u8] = b"template-example-test-jwt-secret";
+
+pub trait TestContainerBuilderExt {
+    #[must_use]
+    fn with_in_memory_repositories(self) -> Self;
+
+    #[must_use]
+    fn with_services(self) -> Self;
+
+    #[must_use]
+    fn with_policy(self) -> Self;
+}
+
+impl TestContainerBuilderExt for TestContainerBuilder {
+    fn with_in_memory_repositories(self) -> Self {
+        self.with(UserTable::default()).with(SessionTable::default())
+    }
+
+    fn with_services(self) -> Self {
+        self.with(Argon2Service::new())
+            .with(JwtService::new(
+                EncodingKey::from_secret(TEST_JWT_SECRET),
+                DecodingKey::from_secret(TEST_JWT_SECRET),
+            ))
+            .with(SessionLifetime::default())
+    }
+
+    fn with_policy(self) -> Self {
+        self.policy(Modules::setup_policy())
+    }
+}
+
+impl_has! {
+    struct: TestContainer,
+    UserTable: |s| s.extension(),
+    SessionTable: |s| s.extension(),
+    Argon2Service: |s| s.extension(),
+    JwtService: |s| s.extension(),
+    SessionLifetime: |s| s.extension(),
+}
+
+impl_repositories! {
+    struct: TestContainer,
+    DelegateUserRepository: InMemoryRepositoryImpl,
+    SessionRepositoryImpl: |_s| &InMemoryRepositoryImpl,
+}
+
+impl_services! {
+    struct: TestContainer,
+    DelegateSecretHasherService: Argon2Service,
+    DelegateTokenService: JwtService,
+}
@@ -1,4 +1,10 @@
-use lib::infrastructure::persistence::repository_impl_struct;
+use lib::infrastructure::persistence::{SqlxPool, repository_impl_struct};
+use sqlx::Postgres;
 
 repository_impl_struct!(Postgres);
 repository_impl_struct!(Redis);
+repository_impl_struct!(CachedPostgres);
+#[cfg(feature = "testkit")]
+repository_impl_struct!(InMemory);
+
+pub type PostgresTransactionManager = SqlxPool<Postgres>;
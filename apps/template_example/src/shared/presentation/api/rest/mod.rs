@@ -1,6 +1,27 @@
+use lib::{
+    presentation::api::rest::authorization::{
+        RequiredPermission, document_permission,
+    },
+    utoipa_axum::router::UtoipaMethodRouter,
+};
+
 pub(crate) mod errors;
 pub(crate) mod extractors;
 
 pub(crate) use errors::ApiError;
 
 pub const B2C_TAG: &str = "B2C";
+
+pub const USER_SECURITY: &str = "user";
+
+#[must_use]
+pub fn secured<R, S>(
+    (schemas, mut paths, router): UtoipaMethodRouter<S>,
+) -> UtoipaMethodRouter<S>
+where
+    R: RequiredPermission,
+{
+    document_permission::<R>(&mut paths, USER_SECURITY);
+
+    (schemas, paths, router)
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use lib::{
+    application::clock::Clock as _, axum::http::StatusCode,
+    testkit::transaction::TransactionOutcome,
+};
+use template_example::features::user_auth::domain::session::SessionLifetime;
+
+use self::common::{PASSWORD, TestResult, client, container, sign_in, sign_up};
+
+mod common;
+
+#[tokio::test]
+async fn sign_up_creates_user_and_returns_token() -> TestResult {
+    let container = container().build();
+    let client = client(container.clone());
+
+    let response = sign_up(&client, "maria@example.com").await?;
+
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text());
+    assert!(
+        !common::token(&response)?.is_empty(),
+        "response contains a token"
+    );
+    assert_eq!(
+        container.outbox().entries().len(),
+        1,
+        "user created event is recorded"
+    );
+    assert_eq!(
+        container.outbox().jobs().len(),
+        1,
+        "welcome email is enqueued"
+    );
+    assert_eq!(
+        container.transactions().begun(),
+        1,
+        "sign up runs in a transaction"
+    );
+    assert_eq!(
+        container.transactions().outcomes(),
+        [TransactionOutcome::Committed],
+        "sign up commits its transaction"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_up_rejects_taken_email() -> TestResult {
+    let client = client(container().build());
+
+    sign_up(&client, "maria@example.com").await?;
+    let response = sign_up(&client, "maria@example.com").await?;
+
+    assert_eq!(response.status, StatusCode::CONFLICT, "{}", response.text());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_in_returns_token_for_valid_credentials() -> TestResult {
+    let client = client(container().build());
+
+    sign_up(&client, "maria@example.com").await?;
+    let response = sign_in(&client, "maria@example.com", PASSWORD).await?;
+
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text());
+    assert!(
+        !common::token(&response)?.is_empty(),
+        "response contains a token"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_in_rejects_invalid_credentials() -> TestResult {
+    let client = client(container().build());
+
+    sign_up(&client, "maria@example.com").await?;
+    let wrong_password =
+        sign_in(&client, "maria@example.com", "WrongPa$$w0rd!").await?;
+    let unknown_email = sign_in(&client, "nobody@example.com", PASSWORD).await?;
+
+    assert_eq!(
+        wrong_password.status,
+        StatusCode::UNAUTHORIZED,
+        "{}",
+        wrong_password.text()
+    );
+    assert_eq!(
+        unknown_email.status,
+        StatusCode::UNAUTHORIZED,
+        "{}",
+        unknown_email.text()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn session_expires_after_configured_lifetime() -> TestResult {
+    let container = container()
+        .with(SessionLifetime::new(Duration::from_secs(60)))
+        .build();
+    let client = client(container.clone());
+
+    let token = common::token(&sign_up(&client, "maria@example.com").await?)?;
+
+    container.clock().advance(Duration::from_secs(30));
+    let fresh = client.get("/user/profile").bearer(&token).send().await?;
+
+    container.clock().advance(Duration::from_secs(120));
+    let expired = client.get("/user/profile").bearer(&token).send().await?;
+
+    assert_eq!(fresh.status, StatusCode::OK, "{}", fresh.text());
+    assert_eq!(
+        expired.status,
+        StatusCode::UNAUTHORIZED,
+        "{}",
+        expired.text()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn audit_records_use_the_injected_clock() -> TestResult {
+    let container = container().build();
+    let client = client(container.clone());
+
+    sign_up(&client, "maria@example.com").await?;
+    sign_in(&client, "maria@example.com", PASSWORD).await?;
+
+    let records = container.audit_store().records();
+
+    assert_eq!(records.len(), 2, "sign up and sign in are audited");
+    assert!(
+        records
+            .iter()
+            .all(|record| record.occurred_at == container.clock().now()),
+        "records are stamped by the test clock"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use entrait::Impl;
+use lib::testkit::{
+    container::{TestContainer, TestContainerBuilder},
+    http::{TestClient, TestClientError, TestResponse},
+};
+use serde_json::{Value, json};
+use template_example::{
+    bootstrappers::api::rest::router,
+    modules::testkit::TestContainerBuilderExt as _,
+};
+
+pub type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+pub const PASSWORD: &str = "HardPa$$w0rd!iamthewinner";
+
+#[must_use]
+pub fn container() -> TestContainerBuilder {
+    TestContainer::builder()
+        .with_in_memory_repositories()
+        .with_services()
+        .with_policy()
+}
+
+#[must_use]
+pub fn client(container: TestContainer) -> TestClient {
+    TestClient::from_openapi(router(), Impl::new(container))
+}
+
+pub async fn sign_up(
+    client: &TestClient,
+    email: &str,
+) -> Result<TestResponse, TestClientError> {
+    client
+        .post("/user/auth/sign-up")
+        .json(&json!({
+            "name": "Мария",
+            "surname": "Федотова",
+            "email": email,
+            "password": PASSWORD,
+            "other": {
+                "age": 25,
+                "country": "RU",
+            },
+        }))
+        .send()
+        .await
+}
+
+pub async fn sign_in(
+    client: &TestClient,
+    email: &str,
+    password: &str,
+) -> Result<TestResponse, TestClientError> {
+    client
+        .post("/user/auth/sign-in")
+        .json(&json!({
+            "email": email,
+            "password": password,
+        }))
+        .send()
+        .await
+}
+
+pub fn token(response: &TestResponse) -> Result<String, serde_json::Error> {
+    response.json::<Value>().map(|body| {
+        body.get("token")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned()
+    })
+}
@@ -0,0 +1,123 @@
+use std::error::Error;
+
+use lib::{
+    axum::http::StatusCode,
+    domain::Id,
+    testkit::{container::TestContainer, http::TestClient},
+    uuid::Uuid,
+};
+use template_example::features::user::infrastructure::persistence::{
+    in_memory::UserTable,
+    postgres::entity::{StoredUser, role::StoredUserRole},
+};
+
+use self::common::{
+    PASSWORD, TestResult, client, container, sign_in, sign_up, token,
+};
+
+mod common;
+
+fn stored_user(
+    container: &TestContainer,
+    email: &str,
+) -> Result<StoredUser, Box<dyn Error>> {
+    container
+        .extension::<UserTable>()
+        .find(|user| user.email == email)
+        .ok_or_else(|| format!("user `{email}` not found").into())
+}
+
+fn user_id(
+    container: &TestContainer,
+    email: &str,
+) -> Result<Uuid, Box<dyn Error>> {
+    Ok(stored_user(container, email)?.id)
+}
+
+fn promote(container: &TestContainer, email: &str) -> TestResult {
+    let mut user = stored_user(container, email)?;
+
+    user.role = StoredUserRole::Admin;
+    container
+        .extension::<UserTable>()
+        .insert(Id::<StoredUser>::new(user.id), user);
+
+    Ok(())
+}
+
+async fn session_token(
+    client: &TestClient,
+    email: &str,
+) -> Result<String, Box<dyn Error>> {
+    sign_up(client, email).await?;
+
+    Ok(token(&sign_in(client, email, PASSWORD).await?)?)
+}
+
+#[tokio::test]
+async fn admin_can_list_users() -> TestResult {
+    let container = container().build();
+    let client = client(container.clone());
+
+    sign_up(&client, "admin@example.com").await?;
+    promote(&container, "admin@example.com")?;
+    let admin = token(&sign_in(&client, "admin@example.com", PASSWORD).await?)?;
+
+    let response = client.get("/user/users").bearer(&admin).send().await?;
+
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_cannot_list_users() -> TestResult {
+    let client = client(container().build());
+
+    let user = session_token(&client, "maria@example.com").await?;
+
+    let response = client.get("/user/users").bearer(&user).send().await?;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN, "{}", response.text());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_can_read_own_user() -> TestResult {
+    let container = container().build();
+    let client = client(container.clone());
+
+    let user = session_token(&client, "maria@example.com").await?;
+    let id = user_id(&container, "maria@example.com")?;
+
+    let response = client
+        .get(&format!("/user/users/{id}"))
+        .bearer(&user)
+        .send()
+        .await?;
+
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_cannot_read_another_user() -> TestResult {
+    let container = container().build();
+    let client = client(container.clone());
+
+    let user = session_token(&client, "maria@example.com").await?;
+    sign_up(&client, "ivan@example.com").await?;
+    let id = user_id(&container, "ivan@example.com")?;
+
+    let response = client
+        .get(&format!("/user/users/{id}"))
+        .bearer(&user)
+        .send()
+        .await?;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN, "{}", response.text());
+
+    Ok(())
+}
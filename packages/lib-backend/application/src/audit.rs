@@ -0,0 +1,283 @@
+use std::{
+    fmt::{self, Display},
+    net::IpAddr,
+    sync::Arc,
+};
+
+use chrono::{DateTime, Utc};
+use domain::{
+    Id,
+    pagination::{Page, PageRequest},
+};
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    clock::{SharedClock, SystemClock},
+    di::Has,
+    id::{SharedIdGenerator, UuidV7Generator},
+};
+
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[serde(transparent)]
+pub struct AuditAction(&'static str);
+
+impl AuditAction {
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(tag = "kind", content = "id", rename_all = "snake_case")]
+pub enum Actor {
+    Anonymous,
+    System,
+    User(Uuid),
+}
+
+impl Actor {
+    #[must_use]
+    pub const fn user<T>(id: Id<T>) -> Self {
+        Self::User(id.value)
+    }
+
+    #[must_use]
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::Anonymous => "anonymous",
+            Self::System => "system",
+            Self::User(_) => "user",
+        }
+    }
+
+    #[must_use]
+    pub const fn id(self) -> Option<Uuid> {
+        match self {
+            Self::User(id) => Some(id),
+            Self::Anonymous | Self::System => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct AuditTarget {
+    pub kind: String,
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+impl AuditOutcome {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct AuditContext {
+    pub request_id: Option<Uuid>,
+    pub ip: Option<IpAddr>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AuditEvent {
+    pub actor: Actor,
+    pub action: AuditAction,
+    pub target: Option<AuditTarget>,
+    pub outcome: AuditOutcome,
+    pub details: Value,
+}
+
+impl AuditEvent {
+    #[must_use]
+    pub const fn new(actor: Actor, action: AuditAction) -> Self {
+        Self {
+            actor,
+            action,
+            target: None,
+            outcome: AuditOutcome::Success,
+            details: Value::Null,
+        }
+    }
+
+    #[must_use]
+    pub fn target<T>(mut self, kind: &str, id: T) -> Self
+    where
+        T: Display,
+    {
+        self.target = Some(AuditTarget {
+            kind: kind.to_owned(),
+            id: id.to_string(),
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn failed(mut self) -> Self {
+        self.outcome = AuditOutcome::Failure;
+        self
+    }
+
+    #[must_use]
+    pub fn details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AuditRecord {
+    pub id: Id<Self>,
+    pub occurred_at: DateTime<Utc>,
+    pub actor: Actor,
+    pub action: String,
+    pub target: Option<AuditTarget>,
+    pub outcome: AuditOutcome,
+    pub request_id: Option<Uuid>,
+    pub ip: Option<IpAddr>,
+    pub details: Value,
+}
+
+impl AuditRecord {
+    #[must_use]
+    pub fn new(
+        id: Id<Self>,
+        event: AuditEvent,
+        context: AuditContext,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            occurred_at,
+            actor: event.actor,
+            action: event.action.name().to_owned(),
+            target: event.target,
+            outcome: event.outcome,
+            request_id: context.request_id,
+            ip: context.ip,
+            details: event.details,
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct AuditFilter {
+    pub actor: Option<Uuid>,
+    pub action: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+}
+
+pub trait AuditStore: Send + Sync {
+    fn append(&self, record: AuditRecord) -> BoxFuture<'_, Result<(), String>>;
+
+    fn query(
+        &self,
+        filter: AuditFilter,
+        request: PageRequest,
+    ) -> BoxFuture<'_, Result<Page<AuditRecord>, String>>;
+}
+
+#[derive(Clone)]
+pub struct AuditLog {
+    store: Arc<dyn AuditStore>,
+    context: fn() -> AuditContext,
+    clock: SharedClock,
+    id_generator: SharedIdGenerator,
+}
+
+impl AuditLog {
+    #[must_use]
+    pub fn new<S>(store: S) -> Self
+    where
+        S: AuditStore + 'static,
+    {
+        Self {
+            store: Arc::new(store),
+            context: AuditContext::default,
+            clock: SystemClock::shared(),
+            id_generator: UuidV7Generator::shared(),
+        }
+    }
+
+    #[must_use]
+    pub const fn context(mut self, context: fn() -> AuditContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    #[must_use]
+    pub fn clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub fn id_generator(mut self, id_generator: SharedIdGenerator) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub async fn record(&self, event: AuditEvent) {
+        let record = AuditRecord::new(
+            Id::new(self.id_generator.next_uuid()),
+            event,
+            (self.context)(),
+            self.clock.now(),
+        );
+        let action = record.action.clone();
+
+        if let Err(error) = self.store.append(record).await {
+            tracing::error!(
+                action,
+                error = %error,
+                "failed to append audit record"
+            );
+            metrics::counter!("audit_append_failures_total").increment(1);
+        }
+    }
+
+    pub async fn query(
+        &self,
+        filter: AuditFilter,
+        request: PageRequest,
+    ) -> Result<Page<AuditRecord>, String> {
+        self.store.query(filter, request).await
+    }
+}
+
+pub trait HasAuditExt {
+    fn audit(&self, event: AuditEvent) -> impl Future<Output = ()> + Send;
+}
+
+impl<D> HasAuditExt for D
+where
+    D: Has<AuditLog>,
+{
+    fn audit(&self, event: AuditEvent) -> impl Future<Output = ()> + Send {
+        self.get_dependency().record(event)
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use serde::Serialize;
+
+use crate::di::Has;
+
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[serde(transparent)]
+pub struct Permission(&'static str);
+
+impl Permission {
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[serde(transparent)]
+pub struct Role(&'static str);
+
+impl Role {
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Scope {
+    Any,
+    Own,
+}
+
+pub trait Principal {
+    fn roles(&self) -> &[Role];
+}
+
+pub trait Resource<P>
+where
+    P: Principal,
+{
+    fn is_owned_by(&self, principal: &P) -> bool;
+}
+
+#[derive(thiserror::Error, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AuthorizationError {
+    #[error("missing permission `{0}`")]
+    MissingPermission(Permission),
+
+    #[error("permission `{0}` does not cover this resource")]
+    ResourceDenied(Permission),
+}
+
+impl AuthorizationError {
+    #[must_use]
+    pub const fn permission(self) -> Permission {
+        match self {
+            Self::MissingPermission(permission)
+            | Self::ResourceDenied(permission) => permission,
+        }
+    }
+}
+
+type Grants = HashMap<Role, HashMap<Permission, Scope>>;
+
+#[derive(Clone, Default)]
+pub struct Policy {
+    grants: Arc<Grants>,
+}
+
+impl Policy {
+    #[must_use]
+    pub fn builder() -> PolicyBuilder {
+        PolicyBuilder::default()
+    }
+
+    #[must_use]
+    pub fn scope_of<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Option<Scope>
+    where
+        P: Principal,
+    {
+        principal
+            .roles()
+            .iter()
+            .filter_map(|role| self.grants.get(role)?.get(&permission))
+            .copied()
+            .reduce(|left, right| {
+                if left == Scope::Any || right == Scope::Any {
+                    Scope::Any
+                } else {
+                    Scope::Own
+                }
+            })
+    }
+
+    #[must_use]
+    pub fn permissions_of<P>(&self, principal: &P) -> HashSet<Permission>
+    where
+        P: Principal,
+    {
+        principal
+            .roles()
+            .iter()
+            .filter_map(|role| self.grants.get(role))
+            .flat_map(HashMap::keys)
+            .copied()
+            .collect()
+    }
+
+    pub fn granted_scope<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<Scope, AuthorizationError>
+    where
+        P: Principal,
+    {
+        self.scope_of(principal, permission)
+            .ok_or(AuthorizationError::MissingPermission(permission))
+    }
+
+    pub fn authorize<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal,
+    {
+        match self.granted_scope(principal, permission)? {
+            Scope::Any => Ok(()),
+            Scope::Own => Err(AuthorizationError::ResourceDenied(permission)),
+        }
+    }
+
+    pub fn authorize_on<P, R>(
+        &self,
+        principal: &P,
+        permission: Permission,
+        resource: &R,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal,
+        R: Resource<P>,
+    {
+        match self.scope_of(principal, permission) {
+            Some(Scope::Any) => Ok(()),
+            Some(Scope::Own) if resource.is_owned_by(principal) => Ok(()),
+            Some(Scope::Own) => {
+                Err(AuthorizationError::ResourceDenied(permission))
+            },
+            None => Err(AuthorizationError::MissingPermission(permission)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PolicyBuilder {
+    grants: Grants,
+}
+
+impl PolicyBuilder {
+    #[must_use]
+    pub fn grant<I>(mut self, role: Role, permissions: I) -> Self
+    where
+        I: IntoIterator<Item = Permission>,
+    {
+        self.insert(role, permissions, Scope::Any);
+        self
+    }
+
+    #[must_use]
+    pub fn grant_own<I>(mut self, role: Role, permissions: I) -> Self
+    where
+        I: IntoIterator<Item = Permission>,
+    {
+        self.insert(role, permissions, Scope::Own);
+        self
+    }
+
+    #[must_use]
+    pub fn inherit(mut self, role: Role, parent: Role) -> Self {
+        let inherited = self.grants.get(&parent).cloned().unwrap_or_default();
+
+        for (permission, scope) in inherited {
+            self.insert(role, [permission], scope);
+        }
+
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Policy {
+        Policy {
+            grants: Arc::new(self.grants),
+        }
+    }
+
+    fn insert<I>(&mut self, role: Role, permissions: I, scope: Scope)
+    where
+        I: IntoIterator<Item = Permission>,
+    {
+        let grants = self.grants.entry(role).or_default();
+
+        for permission in permissions {
+            let current = grants.entry(permission).or_insert(scope);
+            if scope == Scope::Any {
+                *current = Scope::Any;
+            }
+        }
+    }
+}
+
+pub trait HasAuthorizationExt {
+    fn granted_scope<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<Scope, AuthorizationError>
+    where
+        P: Principal;
+
+    fn authorize<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal;
+
+    fn authorize_on<P, R>(
+        &self,
+        principal: &P,
+        permission: Permission,
+        resource: &R,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal,
+        R: Resource<P>;
+}
+
+impl<D> HasAuthorizationExt for D
+where
+    D: Has<Policy>,
+{
+    fn granted_scope<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<Scope, AuthorizationError>
+    where
+        P: Principal,
+    {
+        self.get_dependency().granted_scope(principal, permission)
+    }
+
+    fn authorize<P>(
+        &self,
+        principal: &P,
+        permission: Permission,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal,
+    {
+        self.get_dependency().authorize(principal, permission)
+    }
+
+    fn authorize_on<P, R>(
+        &self,
+        principal: &P,
+        permission: Permission,
+        resource: &R,
+    ) -> Result<(), AuthorizationError>
+    where
+        P: Principal,
+        R: Resource<P>,
+    {
+        self.get_dependency()
+            .authorize_on(principal, permission, resource)
+    }
+}
+
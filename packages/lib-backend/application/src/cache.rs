@@ -0,0 +1,55 @@
+use std::{marker::PhantomData, time::Duration};
+
+use derive_where::derive_where;
+
+pub const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(30);
+pub const DEFAULT_LOCK_TTL: Duration = Duration::from_secs(5);
+pub const DEFAULT_LOCK_WAIT: Duration = Duration::from_secs(1);
+
+#[derive_where(Clone, Copy, Debug)]
+pub struct CachePolicy<R> {
+    pub name: &'static str,
+    pub ttl: Duration,
+    pub negative_ttl: Option<Duration>,
+    pub lock_ttl: Duration,
+    pub lock_wait: Duration,
+    repository: PhantomData<fn() -> R>,
+}
+
+impl<R> CachePolicy<R> {
+    #[must_use]
+    pub const fn new(name: &'static str, ttl: Duration) -> Self {
+        Self {
+            name,
+            ttl,
+            negative_ttl: Some(DEFAULT_NEGATIVE_TTL),
+            lock_ttl: DEFAULT_LOCK_TTL,
+            lock_wait: DEFAULT_LOCK_WAIT,
+            repository: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn negative_ttl(mut self, negative_ttl: Duration) -> Self {
+        self.negative_ttl = Some(negative_ttl);
+        self
+    }
+
+    #[must_use]
+    pub const fn without_negative_caching(mut self) -> Self {
+        self.negative_ttl = None;
+        self
+    }
+
+    #[must_use]
+    pub const fn lock_ttl(mut self, lock_ttl: Duration) -> Self {
+        self.lock_ttl = lock_ttl;
+        self
+    }
+
+    #[must_use]
+    pub const fn lock_wait(mut self, lock_wait: Duration) -> Self {
+        self.lock_wait = lock_wait;
+        self
+    }
+}
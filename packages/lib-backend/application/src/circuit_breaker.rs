@@ -0,0 +1,294 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use tap::Pipe as _;
+
+use crate::health::{CheckResult, HealthCheck};
+
+pub const DEFAULT_FAILURE_RATE_THRESHOLD: u8 = 50;
+pub const DEFAULT_MINIMUM_CALLS: usize = 10;
+pub const DEFAULT_WINDOW_SIZE: usize = 20;
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+pub const DEFAULT_HALF_OPEN_MAX_CALLS: u32 = 1;
+
+#[derive(Serialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    const fn as_gauge(self) -> f64 {
+        match self {
+            Self::Closed => 0.0,
+            Self::HalfOpen => 1.0,
+            Self::Open => 2.0,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CircuitBreakerError<E> {
+    #[error("circuit breaker `{0}` is open")]
+    Open(&'static str),
+
+    #[error("{0}")]
+    Operation(E),
+}
+
+struct Inner {
+    state: CircuitState,
+    outcomes: VecDeque<bool>,
+    opened_at: Option<Instant>,
+    half_open_calls: u32,
+}
+
+#[derive(Clone, Copy)]
+struct Settings {
+    failure_rate_threshold: u8,
+    minimum_calls: usize,
+    window_size: usize,
+    cooldown: Duration,
+    half_open_max_calls: u32,
+}
+
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    name: &'static str,
+    settings: Settings,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl CircuitBreaker {
+    #[must_use]
+    pub fn new(name: &'static str) -> Self {
+        metrics::gauge!("circuit_breaker_state", "name" => name)
+            .set(CircuitState::Closed.as_gauge());
+
+        Self {
+            name,
+            settings: Settings {
+                failure_rate_threshold: DEFAULT_FAILURE_RATE_THRESHOLD,
+                minimum_calls: DEFAULT_MINIMUM_CALLS,
+                window_size: DEFAULT_WINDOW_SIZE,
+                cooldown: DEFAULT_COOLDOWN,
+                half_open_max_calls: DEFAULT_HALF_OPEN_MAX_CALLS,
+            },
+            inner: Arc::new(Mutex::new(Inner {
+                state: CircuitState::Closed,
+                outcomes: VecDeque::new(),
+                opened_at: None,
+                half_open_calls: 0,
+            })),
+        }
+    }
+
+    #[must_use]
+    pub fn failure_rate_threshold(mut self, percent: u8) -> Self {
+        self.settings.failure_rate_threshold = percent.min(100);
+        self
+    }
+
+    #[must_use]
+    pub const fn minimum_calls(mut self, minimum_calls: usize) -> Self {
+        self.settings.minimum_calls = minimum_calls;
+        self
+    }
+
+    #[must_use]
+    pub const fn window_size(mut self, window_size: usize) -> Self {
+        self.settings.window_size = window_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.settings.cooldown = cooldown;
+        self
+    }
+
+    #[must_use]
+    pub const fn half_open_max_calls(mut self, max_calls: u32) -> Self {
+        self.settings.half_open_max_calls = max_calls;
+        self
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+        inner.state
+    }
+
+    pub async fn call<F, T, E>(
+        &self,
+        operation: F,
+    ) -> Result<T, CircuitBreakerError<E>>
+    where
+        F: Future<Output = Result<T, E>>,
+    {
+        let mut permit = self.permit()?;
+
+        let result = operation.await;
+        permit.record(result.is_ok());
+
+        result.map_err(CircuitBreakerError::Operation)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn permit<E>(&self) -> Result<Permit<'_>, CircuitBreakerError<E>> {
+        let mut inner = self.lock();
+        self.refresh(&mut inner);
+
+        let admitted = match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                let admitted =
+                    inner.half_open_calls < self.settings.half_open_max_calls;
+                if admitted {
+                    inner.half_open_calls =
+                        inner.half_open_calls.saturating_add(1);
+                }
+                admitted
+            },
+        };
+
+        if !admitted {
+            metrics::counter!(
+                "circuit_breaker_rejected_total",
+                "name" => self.name
+            )
+            .increment(1);
+
+            return CircuitBreakerError::Open(self.name).pipe(Err);
+        }
+
+        Ok(Permit {
+            breaker: self,
+            half_open: inner.state == CircuitState::HalfOpen,
+            recorded: false,
+        })
+    }
+
+    fn refresh(&self, inner: &mut Inner) {
+        let cooled_down = inner.opened_at.is_some_and(|opened_at| {
+            opened_at.elapsed() >= self.settings.cooldown
+        });
+
+        if inner.state == CircuitState::Open && cooled_down {
+            self.transition(inner, CircuitState::HalfOpen);
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut inner = self.lock();
+
+        match inner.state {
+            CircuitState::HalfOpen if success => {
+                self.transition(&mut inner, CircuitState::Closed);
+            },
+            CircuitState::HalfOpen => {
+                self.transition(&mut inner, CircuitState::Open);
+            },
+            CircuitState::Closed => {
+                inner.outcomes.push_back(success);
+                while inner.outcomes.len() > self.settings.window_size {
+                    inner.outcomes.pop_front();
+                }
+
+                if self.should_trip(&inner) {
+                    self.transition(&mut inner, CircuitState::Open);
+                }
+            },
+            CircuitState::Open => {},
+        }
+    }
+
+    fn should_trip(&self, inner: &Inner) -> bool {
+        let calls = inner.outcomes.len();
+        if calls < self.settings.minimum_calls.max(1) {
+            return false;
+        }
+
+        let failures =
+            inner.outcomes.iter().filter(|success| !**success).count();
+        let threshold = usize::from(self.settings.failure_rate_threshold);
+
+        failures.saturating_mul(100) >= calls.saturating_mul(threshold)
+    }
+
+    fn transition(&self, inner: &mut Inner, state: CircuitState) {
+        if inner.state == state {
+            return;
+        }
+
+        tracing::warn!(
+            name = self.name,
+            from = ?inner.state,
+            to = ?state,
+            "circuit breaker state changed"
+        );
+        metrics::gauge!("circuit_breaker_state", "name" => self.name)
+            .set(state.as_gauge());
+
+        inner.state = state;
+        inner.half_open_calls = 0;
+        inner.outcomes.clear();
+        inner.opened_at = (state == CircuitState::Open).then(Instant::now);
+    }
+}
+
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    half_open: bool,
+    recorded: bool,
+}
+
+impl Permit<'_> {
+    fn record(&mut self, success: bool) {
+        self.recorded = true;
+        self.breaker.record(success);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.recorded || !self.half_open {
+            return;
+        }
+
+        let mut inner = self.breaker.lock();
+        inner.half_open_calls = inner.half_open_calls.saturating_sub(1);
+    }
+}
+
+impl HealthCheck for CircuitBreaker {
+    fn health_check(
+        &self,
+    ) -> impl Future<Output = CheckResult> + Send + 'static {
+        let result = match self.state() {
+            CircuitState::Open => CircuitBreakerError::<String>::Open(self.name)
+                .to_string()
+                .pipe(Err),
+            CircuitState::Closed | CircuitState::HalfOpen => Ok(()),
+        };
+
+        async move { result }
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::di::Has;
+
+pub trait Clock: Send + Sync + Debug {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn monotonic(&self) -> Instant;
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SystemClock;
+
+impl SystemClock {
+    #[must_use]
+    pub fn shared() -> SharedClock {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn monotonic(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TestClock {
+    state: Arc<Mutex<(DateTime<Utc>, Instant)>>,
+}
+
+impl TestClock {
+    #[must_use]
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new((now, Instant::now()))),
+        }
+    }
+
+    #[must_use]
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).0 = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut state =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let (now, monotonic) = *state;
+        *state = (
+            TimeDelta::from_std(by)
+                .ok()
+                .and_then(|by| now.checked_add_signed(by))
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            monotonic.checked_add(by).unwrap_or(monotonic),
+        );
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new(DateTime::<Utc>::UNIX_EPOCH)
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).0
+    }
+
+    fn monotonic(&self) -> Instant {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).1
+    }
+}
+
+pub trait HasClockExt {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn monotonic(&self) -> Instant;
+}
+
+impl<D> HasClockExt for D
+where
+    D: Has<SharedClock>,
+{
+    fn now(&self) -> DateTime<Utc> {
+        self.get_dependency().now()
+    }
+
+    fn monotonic(&self) -> Instant {
+        self.get_dependency().monotonic()
+    }
+}
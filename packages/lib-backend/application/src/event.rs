@@ -0,0 +1,284 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::Display,
+    future::Future,
+    hash::{DefaultHasher, Hash, Hasher as _},
+    panic::AssertUnwindSafe,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+use tap::Pipe as _;
+use tokio::sync::{
+    mpsc::{self, UnboundedReceiver, UnboundedSender},
+    oneshot,
+};
+use tracing::{Instrument as _, Span};
+
+use crate::{di::Has, shutdown::ShutdownHook};
+
+pub const DEFAULT_SHARDS: usize = 8;
+
+pub trait DomainEvent: Clone + Send + Sync + 'static {
+    const NAME: &'static str;
+
+    fn aggregate_id(&self) -> impl Display + Hash;
+}
+
+type Payload = Arc<dyn Any + Send + Sync>;
+
+type Handler = Arc<
+    dyn Fn(Payload) -> BoxFuture<'static, Result<(), String>> + Send + Sync,
+>;
+
+struct Subscriber {
+    name: &'static str,
+    handler: Handler,
+}
+
+struct Envelope {
+    type_id: TypeId,
+    event: &'static str,
+    payload: Payload,
+    span: Span,
+}
+
+enum Message {
+    Event(Envelope),
+    Drain(oneshot::Sender<()>),
+}
+
+pub struct EventBusBuilder {
+    shards: usize,
+    subscribers: HashMap<TypeId, Vec<Subscriber>>,
+}
+
+impl EventBusBuilder {
+    #[must_use]
+    pub fn shards(mut self, shards: usize) -> Self {
+        self.shards = shards.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn subscribe<E, H, Fut, HandlerError>(
+        mut self,
+        name: &'static str,
+        handler: H,
+    ) -> Self
+    where
+        E: DomainEvent,
+        H: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+        HandlerError: Display,
+    {
+        let handler: Handler = Arc::new(move |payload: Payload| {
+            let Some(event) = payload.downcast_ref::<E>().cloned() else {
+                return async { Ok(()) }.boxed();
+            };
+
+            handler(event)
+                .map(|result| result.map_err(|error| error.to_string()))
+                .boxed()
+        });
+
+        self.subscribers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Subscriber {
+                name,
+                handler,
+            });
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> EventBus {
+        let subscribers = Arc::new(self.subscribers);
+
+        let shards = (0..self.shards)
+            .map(|_| {
+                let (sender, receiver) = mpsc::unbounded_channel();
+                tokio::spawn(dispatch(receiver, Arc::clone(&subscribers)));
+                sender
+            })
+            .collect::<Vec<_>>()
+            .pipe(Arc::from);
+
+        EventBus {
+            shards,
+            closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+async fn dispatch(
+    mut receiver: UnboundedReceiver<Message>,
+    subscribers: Arc<HashMap<TypeId, Vec<Subscriber>>>,
+) {
+    while let Some(message) = receiver.recv().await {
+        let envelope = match message {
+            Message::Event(envelope) => envelope,
+            Message::Drain(done) => {
+                if done.send(()).is_err() {
+                    tracing::debug!("event bus drain waiter went away");
+                }
+                break;
+            },
+        };
+
+        let Some(handlers) = subscribers.get(&envelope.type_id) else {
+            continue;
+        };
+
+        for subscriber in handlers {
+            handle(&envelope, subscriber).await;
+        }
+    }
+}
+
+async fn handle(envelope: &Envelope, subscriber: &Subscriber) {
+    let span = tracing::info_span!(
+        parent: &envelope.span,
+        "event.handle",
+        event = envelope.event,
+        subscriber = subscriber.name,
+    );
+
+    let payload = Arc::clone(&envelope.payload);
+    let result =
+        AssertUnwindSafe(async { (subscriber.handler)(payload).await })
+            .catch_unwind()
+            .instrument(span.clone())
+            .await
+            .unwrap_or_else(|panic| {
+                Err(format!("panicked: {}", panic_message(panic.as_ref())))
+            });
+
+    if let Err(error) = result {
+        span.in_scope(|| {
+            tracing::error!(error = %error, "event subscriber failed");
+        });
+        metrics::counter!(
+            "event_subscriber_failures_total",
+            "event" => envelope.event,
+            "subscriber" => subscriber.name
+        )
+        .increment(1);
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+#[derive(Clone)]
+pub struct EventBus {
+    shards: Arc<[UnboundedSender<Message>]>,
+    closed: Arc<AtomicBool>,
+}
+
+impl EventBus {
+    #[must_use]
+    pub fn builder() -> EventBusBuilder {
+        EventBusBuilder {
+            shards: DEFAULT_SHARDS,
+            subscribers: HashMap::new(),
+        }
+    }
+
+    pub fn publish<E>(&self, event: E)
+    where
+        E: DomainEvent,
+    {
+        let mut hasher = DefaultHasher::new();
+        event.aggregate_id().hash(&mut hasher);
+
+        let shard = u64::try_from(self.shards.len())
+            .ok()
+            .and_then(|shards| hasher.finish().checked_rem(shards))
+            .and_then(|shard| usize::try_from(shard).ok())
+            .and_then(|shard| self.shards.get(shard));
+
+        let Some(shard) = shard else {
+            return;
+        };
+
+        if self.is_closed() {
+            tracing::warn!(
+                event = E::NAME,
+                "event bus is closed, dropping event"
+            );
+            return;
+        }
+
+        let envelope = Envelope {
+            type_id: TypeId::of::<E>(),
+            event: E::NAME,
+            payload: Arc::new(event),
+            span: Span::current(),
+        };
+
+        if shard.send(Message::Event(envelope)).is_err() {
+            tracing::error!(
+                event = E::NAME,
+                "event dispatcher is closed, dropping event"
+            );
+        }
+    }
+
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    pub async fn drain(&self) {
+        self.closed.store(true, Ordering::Release);
+
+        let pending = self.shards.iter().filter_map(|shard| {
+            let (done, drained) = oneshot::channel();
+            shard.send(Message::Drain(done)).ok().map(|()| drained)
+        });
+
+        future::join_all(pending).await;
+    }
+}
+
+impl ShutdownHook for EventBus {
+    fn shutdown(&self) -> BoxFuture<'_, Result<(), String>> {
+        async {
+            self.drain().await;
+            Ok(())
+        }
+        .boxed()
+    }
+}
+
+pub trait HasEventBusExt {
+    fn publish<E>(&self, event: E)
+    where
+        E: DomainEvent;
+}
+
+impl<D> HasEventBusExt for D
+where
+    D: Has<EventBus>,
+{
+    fn publish<E>(&self, event: E)
+    where
+        E: DomainEvent,
+    {
+        self.get_dependency().publish(event);
+    }
+}
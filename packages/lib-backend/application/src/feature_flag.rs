@@ -0,0 +1,360 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::{self, Display},
+    path::PathBuf,
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
+
+use domain::Id;
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shutdown::Shutdown;
+
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+pub const DEFAULT_ENV_PREFIX: &str = "FEATURE_FLAG_";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[serde(transparent)]
+pub struct FeatureFlag(&'static str);
+
+impl FeatureFlag {
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for FeatureFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Debug)]
+#[serde(default)]
+pub struct FlagDefinition {
+    pub enabled: bool,
+    pub percentage: Option<u8>,
+    pub allow: HashSet<Uuid>,
+    pub deny: HashSet<Uuid>,
+}
+
+impl FlagDefinition {
+    #[must_use]
+    pub fn boolean(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn percentage(percentage: u8) -> Self {
+        Self {
+            enabled: true,
+            percentage: Some(percentage.min(100)),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn allow<T>(mut self, subject: Id<T>) -> Self {
+        self.allow.insert(subject.value);
+        self
+    }
+
+    #[must_use]
+    pub fn deny<T>(mut self, subject: Id<T>) -> Self {
+        self.deny.insert(subject.value);
+        self
+    }
+
+    #[must_use]
+    pub fn evaluate(&self, flag: FeatureFlag, subject: Option<Uuid>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        if let Some(subject) = subject {
+            if self.deny.contains(&subject) {
+                return false;
+            }
+
+            if self.allow.contains(&subject) {
+                return true;
+            }
+        }
+
+        match self.percentage {
+            None => true,
+            Some(percentage) if percentage >= 100 => true,
+            Some(percentage) => subject
+                .is_some_and(|subject| bucket(flag, subject) < percentage),
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "on" | "1" => Some(Self::boolean(true)),
+            "false" | "off" | "0" => Some(Self::boolean(false)),
+            value => value
+                .strip_suffix('%')
+                .and_then(|percentage| percentage.parse().ok())
+                .map(Self::percentage),
+        }
+    }
+}
+
+fn bucket(flag: FeatureFlag, subject: Uuid) -> u8 {
+    let hash = flag
+        .name()
+        .as_bytes()
+        .iter()
+        .chain(subject.as_bytes())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        });
+
+    hash.checked_rem(100)
+        .and_then(|bucket| u8::try_from(bucket).ok())
+        .unwrap_or_default()
+}
+
+pub type Flags = HashMap<String, FlagDefinition>;
+
+pub trait FlagProvider: Send + Sync {
+    fn load(&self) -> BoxFuture<'_, Result<Flags, String>>;
+}
+
+pub struct EnvFlagProvider {
+    prefix: String,
+}
+
+impl EnvFlagProvider {
+    #[must_use]
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_owned(),
+        }
+    }
+}
+
+impl Default for EnvFlagProvider {
+    fn default() -> Self {
+        Self::new(DEFAULT_ENV_PREFIX)
+    }
+}
+
+impl FlagProvider for EnvFlagProvider {
+    fn load(&self) -> BoxFuture<'_, Result<Flags, String>> {
+        let flags = env::vars_os()
+            .filter_map(|(key, value)| {
+                let key = key.into_string().ok()?;
+                let value = value.into_string().ok()?;
+                let name = key.strip_prefix(&self.prefix)?.to_lowercase();
+
+                let Some(definition) = FlagDefinition::parse(&value) else {
+                    tracing::warn!(
+                        %key,
+                        %value,
+                        "ignoring unparseable feature flag"
+                    );
+                    return None;
+                };
+
+                Some((name, definition))
+            })
+            .collect();
+
+        future::ready(Ok(flags)).boxed()
+    }
+}
+
+pub struct FileFlagProvider {
+    path: PathBuf,
+}
+
+impl FileFlagProvider {
+    #[must_use]
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            path: path.into(),
+        }
+    }
+}
+
+impl FlagProvider for FileFlagProvider {
+    fn load(&self) -> BoxFuture<'_, Result<Flags, String>> {
+        async move {
+            let contents = tokio::fs::read(&self.path).await.map_err(|error| {
+                format!("failed to read `{}`: {error}", self.path.display())
+            })?;
+
+            serde_json::from_slice(&contents).map_err(|error| {
+                format!("failed to parse `{}`: {error}", self.path.display())
+            })
+        }
+        .boxed()
+    }
+}
+
+struct Source {
+    provider: Arc<dyn FlagProvider>,
+    last_loaded: RwLock<Flags>,
+}
+
+#[derive(Clone, Default)]
+pub struct FeatureFlags {
+    defaults: Arc<Flags>,
+    sources: Arc<[Source]>,
+    flags: Arc<RwLock<Flags>>,
+}
+
+impl FeatureFlags {
+    #[must_use]
+    pub fn builder() -> FeatureFlagsBuilder {
+        FeatureFlagsBuilder::default()
+    }
+
+    #[must_use]
+    pub fn is_enabled(&self, flag: FeatureFlag) -> bool {
+        self.evaluate(flag, None)
+    }
+
+    #[must_use]
+    pub fn is_enabled_for<T>(&self, flag: FeatureFlag, subject: Id<T>) -> bool {
+        self.evaluate(flag, Some(subject.value))
+    }
+
+    #[must_use]
+    pub fn snapshot(&self) -> Flags {
+        self.flags
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub async fn refresh(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        for source in self.sources.iter() {
+            match source.provider.load().await {
+                Ok(loaded) => {
+                    *source
+                        .last_loaded
+                        .write()
+                        .unwrap_or_else(PoisonError::into_inner) = loaded;
+                },
+                Err(error) => errors.push(error),
+            }
+        }
+
+        let mut flags = Flags::clone(&self.defaults);
+        for source in self.sources.iter() {
+            flags.extend(
+                source
+                    .last_loaded
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone(),
+            );
+        }
+
+        *self.flags.write().unwrap_or_else(PoisonError::into_inner) = flags;
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    pub async fn run_refresh(self, interval: Duration, shutdown: Shutdown) {
+        while !shutdown.is_triggered() {
+            if let Err(error) = self.refresh().await {
+                tracing::warn!(
+                    error = %error,
+                    "failed to refresh some feature flag providers, \
+                     keeping their last loaded values"
+                );
+            }
+
+            tokio::select! {
+                () = tokio::time::sleep(interval) => {},
+                () = shutdown.triggered() => {},
+            }
+        }
+
+        tracing::info!("feature flag refresher stopped");
+    }
+
+    #[must_use]
+    pub fn evaluate(&self, flag: FeatureFlag, subject: Option<Uuid>) -> bool {
+        self.flags
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(flag.name())
+            .is_some_and(|definition| definition.evaluate(flag, subject))
+    }
+}
+
+#[derive(Default)]
+pub struct FeatureFlagsBuilder {
+    defaults: Flags,
+    providers: Vec<Arc<dyn FlagProvider>>,
+}
+
+impl FeatureFlagsBuilder {
+    #[must_use]
+    pub fn flag(
+        mut self,
+        flag: FeatureFlag,
+        definition: FlagDefinition,
+    ) -> Self {
+        self.defaults.insert(flag.name().to_owned(), definition);
+        self
+    }
+
+    #[must_use]
+    pub fn provider<P>(mut self, provider: P) -> Self
+    where
+        P: FlagProvider + 'static,
+    {
+        self.providers.push(Arc::new(provider));
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> FeatureFlags {
+        FeatureFlags {
+            flags: Arc::new(RwLock::new(self.defaults.clone())),
+            defaults: Arc::new(self.defaults),
+            sources: self
+                .providers
+                .into_iter()
+                .map(|provider| Source {
+                    provider,
+                    last_loaded: RwLock::default(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -1,30 +1,95 @@
 use std::{
+    collections::VecDeque,
     future::Future,
+    sync::{Arc, Mutex as StdMutex, PoisonError},
     time::{Duration, Instant},
 };
 
+use chrono::{DateTime, Utc};
 use futures_util::future::{BoxFuture, join_all};
-use result_like::BoolLike;
 use serde::Serialize;
 use tap::Pipe as _;
-use tokio::time::timeout;
+use tokio::{sync::Mutex, time::timeout};
+
+use crate::clock::{SharedClock, SystemClock};
 
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+pub const DEFAULT_MIN_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+pub const DEFAULT_HISTORY_CAPACITY: usize = 100;
 
 pub type CheckResult = Result<(), String>;
 
-#[derive(BoolLike, Serialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(Serialize, PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Status {
     Healthy,
+    Degraded,
     Unhealthy,
 }
 
+impl Status {
+    #[must_use]
+    pub const fn is_available(self) -> bool {
+        matches!(self, Self::Healthy | Self::Degraded)
+    }
+
+    const fn as_gauge(self) -> f64 {
+        match self {
+            Self::Healthy => 1.0,
+            Self::Degraded => 0.5,
+            Self::Unhealthy => 0.0,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Eq, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Criticality {
+    Critical,
+    NonCritical,
+}
+
 pub trait HealthCheck {
     fn health_check(
         &self,
     ) -> impl Future<Output = CheckResult> + Send + 'static;
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct CheckOptions {
+    criticality: Criticality,
+    timeout: Option<Duration>,
+}
+
+impl CheckOptions {
+    #[must_use]
+    pub const fn critical() -> Self {
+        Self {
+            criticality: Criticality::Critical,
+            timeout: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn non_critical() -> Self {
+        Self {
+            criticality: Criticality::NonCritical,
+            timeout: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Default for CheckOptions {
+    fn default() -> Self {
+        Self::critical()
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckOutcome {
@@ -34,6 +99,8 @@ pub struct CheckOutcome {
 
     pub healthy: bool,
 
+    pub criticality: Criticality,
+
     pub duration_ms: u64,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,12 +116,18 @@ pub struct ReadinessReport {
 
 struct Check {
     name: &'static str,
+    options: CheckOptions,
     future: BoxFuture<'static, CheckResult>,
 }
 
 impl Check {
-    async fn run(self, limit: Duration) -> CheckOutcome {
-        let started = Instant::now();
+    async fn run(
+        self,
+        default_timeout: Duration,
+        clock: SharedClock,
+    ) -> CheckOutcome {
+        let limit = self.options.timeout.unwrap_or(default_timeout);
+        let started = clock.monotonic();
 
         let error = match timeout(limit, self.future).await {
             Ok(Ok(())) => None,
@@ -62,29 +135,60 @@ impl Check {
             Err(_) => Some(format!("timed out after {}ms", limit.as_millis())),
         };
 
-        let status = error.is_none().into();
+        let status = if error.is_none() {
+            Status::Healthy
+        } else {
+            Status::Unhealthy
+        };
 
-        CheckOutcome {
+        let elapsed = clock.monotonic().saturating_duration_since(started);
+        let outcome = CheckOutcome {
             name: self.name,
             status,
             healthy: status.eq(&Status::Healthy),
-            duration_ms: u64::try_from(started.elapsed().as_millis())
+            criticality: self.options.criticality,
+            duration_ms: u64::try_from(elapsed.as_millis())
                 .unwrap_or(u64::MAX),
             error,
+        };
+
+        outcome.record_metrics(elapsed);
+        outcome
+    }
+}
+
+impl CheckOutcome {
+    fn record_metrics(&self, elapsed: Duration) {
+        metrics::gauge!("health_check_status", "check" => self.name)
+            .set(self.status.as_gauge());
+        metrics::histogram!(
+            "health_check_duration_seconds",
+            "check" => self.name
+        )
+        .record(elapsed.as_secs_f64());
+
+        if !self.healthy {
+            metrics::counter!(
+                "health_check_failures_total",
+                "check" => self.name
+            )
+            .increment(1);
         }
     }
 }
 
 pub struct Readiness {
     timeout: Duration,
+    clock: SharedClock,
     checks: Vec<Check>,
 }
 
 impl Readiness {
     #[must_use]
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             timeout: DEFAULT_TIMEOUT,
+            clock: SystemClock::shared(),
             checks: Vec::new(),
         }
     }
@@ -96,12 +200,32 @@ impl Readiness {
     }
 
     #[must_use]
-    pub fn check<F>(mut self, name: &'static str, check: F) -> Self
+    pub fn clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub fn check<F>(self, name: &'static str, check: F) -> Self
+    where
+        F: Future<Output = CheckResult> + Send + 'static,
+    {
+        self.check_with(name, CheckOptions::critical(), check)
+    }
+
+    #[must_use]
+    pub fn check_with<F>(
+        mut self,
+        name: &'static str,
+        options: CheckOptions,
+        check: F,
+    ) -> Self
     where
         F: Future<Output = CheckResult> + Send + 'static,
     {
         self.checks.push(Check {
             name,
+            options,
             future: check.pipe(Box::pin),
         });
         self
@@ -115,15 +239,48 @@ impl Readiness {
         self.check(name, check.health_check())
     }
 
+    #[must_use]
+    pub fn probe_with<C>(
+        self,
+        name: &'static str,
+        options: CheckOptions,
+        check: &C,
+    ) -> Self
+    where
+        C: HealthCheck,
+    {
+        self.check_with(name, options, check.health_check())
+    }
+
     pub async fn run(self) -> ReadinessReport {
         let limit = self.timeout;
+        let clock = self.clock;
+
+        let checks = join_all(
+            self.checks
+                .into_iter()
+                .map(|check| check.run(limit, Arc::clone(&clock))),
+        )
+        .await;
+
+        let failing = |criticality| {
+            checks.iter().any(|check| {
+                !check.healthy && check.criticality == criticality
+            })
+        };
+
+        let status = if failing(Criticality::Critical) {
+            Status::Unhealthy
+        } else if failing(Criticality::NonCritical) {
+            Status::Degraded
+        } else {
+            Status::Healthy
+        };
 
-        let checks =
-            join_all(self.checks.into_iter().map(|check| check.run(limit)))
-                .await;
+        metrics::gauge!("readiness_status").set(status.as_gauge());
 
         ReadinessReport {
-            status: checks.iter().all(|check| check.status.to_bool()).into(),
+            status,
             checks,
         }
     }
@@ -134,3 +291,126 @@ impl Default for Readiness {
         Self::new()
     }
 }
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub timestamp_ms: u64,
+    pub status: Status,
+    pub checks: Vec<CheckOutcome>,
+}
+
+#[derive(Clone)]
+pub struct HealthHistory {
+    capacity: usize,
+    entries: Arc<StdMutex<VecDeque<HistoryEntry>>>,
+}
+
+impl HealthHistory {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Arc::new(StdMutex::new(VecDeque::new())),
+        }
+    }
+
+    pub fn record(&self, report: &ReadinessReport, at: DateTime<Utc>) {
+        let timestamp_ms =
+            u64::try_from(at.timestamp_millis()).unwrap_or_default();
+
+        let mut entries =
+            self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+
+        entries.push_back(HistoryEntry {
+            timestamp_ms,
+            status: report.status,
+            checks: report.checks.clone(),
+        });
+    }
+
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<HistoryEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .rev()
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for HealthHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_CAPACITY)
+    }
+}
+
+#[derive(Clone)]
+pub struct ReadinessCache {
+    min_probe_interval: Duration,
+    clock: SharedClock,
+    last: Arc<Mutex<Option<(Instant, ReadinessReport)>>>,
+    history: HealthHistory,
+}
+
+impl ReadinessCache {
+    #[must_use]
+    pub fn new(min_probe_interval: Duration) -> Self {
+        Self {
+            min_probe_interval,
+            clock: SystemClock::shared(),
+            last: Arc::new(Mutex::new(None)),
+            history: HealthHistory::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn history_capacity(mut self, capacity: usize) -> Self {
+        self.history = HealthHistory::new(capacity);
+        self
+    }
+
+    #[must_use]
+    pub fn clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub const fn history(&self) -> &HealthHistory {
+        &self.history
+    }
+
+    pub async fn get_or_probe<F, Fut>(&self, probe: F) -> ReadinessReport
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ReadinessReport>,
+    {
+        let mut last = self.last.lock().await;
+
+        if let Some((probed_at, report)) = last.as_ref()
+            && self.clock.monotonic().saturating_duration_since(*probed_at)
+                < self.min_probe_interval
+        {
+            return report.clone();
+        }
+
+        let report = probe().await;
+        self.history.record(&report, self.clock.now());
+        *last = Some((self.clock.monotonic(), report.clone()));
+
+        report
+    }
+}
+
+impl Default for ReadinessCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_PROBE_INTERVAL)
+    }
+}
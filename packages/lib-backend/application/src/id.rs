@@ -0,0 +1,109 @@
+use std::{
+    fmt::Debug,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use domain::Id;
+use uuid::{Builder, Uuid};
+
+use crate::di::Has;
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+pub trait IdGenerator: Send + Sync + Debug {
+    fn next_uuid(&self) -> Uuid;
+}
+
+pub type SharedIdGenerator = Arc<dyn IdGenerator>;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct UuidV7Generator;
+
+impl UuidV7Generator {
+    #[must_use]
+    pub fn shared() -> SharedIdGenerator {
+        Arc::new(Self)
+    }
+}
+
+impl IdGenerator for UuidV7Generator {
+    fn next_uuid(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct UuidV4Generator;
+
+impl UuidV4Generator {
+    #[must_use]
+    pub fn shared() -> SharedIdGenerator {
+        Arc::new(Self)
+    }
+}
+
+impl IdGenerator for UuidV4Generator {
+    fn next_uuid(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+#[derive(Debug)]
+pub struct SeededIdGenerator {
+    state: AtomicU64,
+}
+
+impl SeededIdGenerator {
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    #[must_use]
+    pub fn shared(seed: u64) -> SharedIdGenerator {
+        Arc::new(Self::new(seed))
+    }
+
+    fn next_u64(&self) -> u64 {
+        let state = self
+            .state
+            .fetch_add(GOLDEN_GAMMA, Ordering::Relaxed)
+            .wrapping_add(GOLDEN_GAMMA);
+
+        let mixed = (state ^ state.wrapping_shr(30))
+            .wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        let mixed = (mixed ^ mixed.wrapping_shr(27))
+            .wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        mixed ^ mixed.wrapping_shr(31)
+    }
+}
+
+impl IdGenerator for SeededIdGenerator {
+    fn next_uuid(&self) -> Uuid {
+        let mut bytes = [0; 16];
+        let (high, low) = bytes.split_at_mut(8);
+        high.copy_from_slice(&self.next_u64().to_be_bytes());
+        low.copy_from_slice(&self.next_u64().to_be_bytes());
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+pub trait HasIdGeneratorExt {
+    fn generate_id<T>(&self) -> Id<T>;
+}
+
+impl<D> HasIdGeneratorExt for D
+where
+    D: Has<SharedIdGenerator>,
+{
+    fn generate_id<T>(&self) -> Id<T> {
+        Id::new(self.get_dependency().next_uuid())
+    }
+}
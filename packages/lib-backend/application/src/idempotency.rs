@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+pub type StoreResult<T> = Result<T, String>;
+
+#[derive(Clone, Debug)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub enum IdempotencyRecord {
+    InFlight {
+        fingerprint: String,
+    },
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+}
+
+impl IdempotencyRecord {
+    #[must_use]
+    pub fn fingerprint(&self) -> &str {
+        match self {
+            Self::InFlight {
+                fingerprint,
+            }
+            | Self::Completed {
+                fingerprint,
+                ..
+            } => fingerprint,
+        }
+    }
+}
+
+pub trait IdempotencyStore: Send + Sync {
+    fn try_lock<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        owner: &'a str,
+        ttl: Duration,
+    ) -> BoxFuture<'a, StoreResult<Option<IdempotencyRecord>>>;
+
+    fn complete<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        response: StoredResponse,
+        ttl: Duration,
+    ) -> BoxFuture<'a, StoreResult<()>>;
+
+    fn release<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        owner: &'a str,
+    ) -> BoxFuture<'a, StoreResult<()>>;
+}
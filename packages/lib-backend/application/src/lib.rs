@@ -1,7 +1,21 @@
 #[doc(hidden)]
 pub use {derive_where::derive_where, pastey};
 
+pub mod audit;
+pub mod authorization;
+pub mod cache;
+pub mod circuit_breaker;
+pub mod clock;
 pub mod di;
+pub mod event;
+pub mod feature_flag;
 pub mod health;
+pub mod id;
+pub mod idempotency;
+pub mod outbox;
+pub mod rate_limit;
 pub mod result;
+pub mod retry;
+pub mod shutdown;
 pub mod timeout;
+pub mod transaction;
@@ -0,0 +1,141 @@
+use std::{sync::Arc, time::Duration};
+
+use futures_util::future::BoxFuture;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{di::Has, event::DomainEvent};
+
+pub const DEFAULT_QUEUE: &str = "default";
+pub const DEFAULT_MAX_ATTEMPTS: i32 = 5;
+
+pub trait QueuedJob: Serialize + DeserializeOwned + Send + Sync + 'static {
+    const NAME: &'static str;
+    const QUEUE: &'static str = DEFAULT_QUEUE;
+    const MAX_ATTEMPTS: i32 = DEFAULT_MAX_ATTEMPTS;
+}
+
+#[derive(Clone, Debug)]
+pub struct OutboxEntry {
+    pub aggregate_id: String,
+    pub event_type: &'static str,
+    pub payload: Value,
+}
+
+impl OutboxEntry {
+    pub fn new<E>(event: &E) -> Result<Self, serde_json::Error>
+    where
+        E: DomainEvent + Serialize,
+    {
+        Ok(Self {
+            aggregate_id: event.aggregate_id().to_string(),
+            event_type: E::NAME,
+            payload: serde_json::to_value(event)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct JobEntry {
+    pub queue: &'static str,
+    pub job_type: &'static str,
+    pub payload: Value,
+    pub max_attempts: i32,
+    pub delay: Duration,
+}
+
+impl JobEntry {
+    pub fn new<J>(job: &J, delay: Duration) -> Result<Self, serde_json::Error>
+    where
+        J: QueuedJob,
+    {
+        Ok(Self {
+            queue: J::QUEUE,
+            job_type: J::NAME,
+            payload: serde_json::to_value(job)?,
+            max_attempts: J::MAX_ATTEMPTS,
+            delay,
+        })
+    }
+}
+
+pub trait Outbox: Send + Sync {
+    fn append(&self, entry: OutboxEntry) -> BoxFuture<'_, Result<(), String>>;
+
+    fn enqueue_job(&self, job: JobEntry) -> BoxFuture<'_, Result<(), String>>;
+}
+
+pub type SharedOutbox = Arc<dyn Outbox>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum OutboxError {
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("failed to write to the outbox: {0}")]
+    Store(String),
+}
+
+pub trait HasOutboxExt {
+    fn enqueue<E>(
+        &self,
+        event: &E,
+    ) -> impl Future<Output = Result<(), OutboxError>> + Send
+    where
+        E: DomainEvent + Serialize;
+
+    fn enqueue_job<J>(
+        &self,
+        job: &J,
+    ) -> impl Future<Output = Result<(), OutboxError>> + Send
+    where
+        J: QueuedJob;
+
+    fn enqueue_job_in<J>(
+        &self,
+        job: &J,
+        delay: Duration,
+    ) -> impl Future<Output = Result<(), OutboxError>> + Send
+    where
+        J: QueuedJob;
+}
+
+impl<D> HasOutboxExt for D
+where
+    D: Has<SharedOutbox> + Sync,
+{
+    async fn enqueue<E>(&self, event: &E) -> Result<(), OutboxError>
+    where
+        E: DomainEvent + Serialize,
+    {
+        let entry = OutboxEntry::new(event)?;
+
+        self.get_dependency()
+            .append(entry)
+            .await
+            .map_err(OutboxError::Store)
+    }
+
+    async fn enqueue_job<J>(&self, job: &J) -> Result<(), OutboxError>
+    where
+        J: QueuedJob,
+    {
+        self.enqueue_job_in(job, Duration::ZERO).await
+    }
+
+    async fn enqueue_job_in<J>(
+        &self,
+        job: &J,
+        delay: Duration,
+    ) -> Result<(), OutboxError>
+    where
+        J: QueuedJob,
+    {
+        let entry = JobEntry::new(job, delay)?;
+
+        self.get_dependency()
+            .enqueue_job(entry)
+            .await
+            .map_err(OutboxError::Store)
+    }
+}
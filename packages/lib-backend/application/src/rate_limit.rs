@@ -0,0 +1,177 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+
+pub const DEFAULT_MAX_TRACKED_KEYS: usize = 10_000;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Quota {
+    pub limit: u32,
+    pub window: Duration,
+}
+
+impl Quota {
+    #[must_use]
+    pub const fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+        }
+    }
+
+    #[must_use]
+    pub const fn per_second(limit: u32) -> Self {
+        Self::new(limit, Duration::from_secs(1))
+    }
+
+    #[must_use]
+    pub const fn per_minute(limit: u32) -> Self {
+        Self::new(limit, Duration::from_secs(60))
+    }
+
+    #[must_use]
+    pub const fn per_hour(limit: u32) -> Self {
+        Self::new(limit, Duration::from_secs(3600))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Decision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_after: Duration,
+}
+
+pub trait RateLimitStore: Send + Sync {
+    fn hit<'a>(
+        &'a self,
+        key: &'a str,
+        quota: Quota,
+    ) -> BoxFuture<'a, Result<Decision, String>>;
+}
+
+#[derive(Clone)]
+pub struct InMemoryRateLimitStore {
+    max_keys: usize,
+    windows: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+}
+
+impl InMemoryRateLimitStore {
+    #[must_use]
+    pub fn new(max_keys: usize) -> Self {
+        Self {
+            max_keys: max_keys.max(1),
+            windows: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    #[must_use]
+    pub fn check(&self, key: &str, quota: Quota) -> Decision {
+        let now = Instant::now();
+        let is_expired =
+            |hit: &Instant| now.duration_since(*hit) >= quota.window;
+
+        let mut windows =
+            self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if windows.len() >= self.max_keys && !windows.contains_key(key) {
+            windows.retain(|_, hits| {
+                hits.back().is_some_and(|hit| !is_expired(hit))
+            });
+        }
+
+        let hits = windows.entry(key.to_owned()).or_default();
+        while hits.front().is_some_and(is_expired) {
+            hits.pop_front();
+        }
+
+        let count = u32::try_from(hits.len()).unwrap_or(u32::MAX);
+        let allowed = count < quota.limit;
+        if allowed {
+            hits.push_back(now);
+        }
+
+        let used = if allowed { count.saturating_add(1) } else { count };
+        let reset_after = hits.front().map_or(Duration::ZERO, |oldest| {
+            quota.window.saturating_sub(now.duration_since(*oldest))
+        });
+
+        Decision {
+            allowed,
+            limit: quota.limit,
+            remaining: quota.limit.saturating_sub(used),
+            reset_after,
+        }
+    }
+}
+
+impl Default for InMemoryRateLimitStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TRACKED_KEYS)
+    }
+}
+
+impl RateLimitStore for InMemoryRateLimitStore {
+    fn hit<'a>(
+        &'a self,
+        key: &'a str,
+        quota: Quota,
+    ) -> BoxFuture<'a, Result<Decision, String>> {
+        future::ready(Ok(self.check(key, quota))).boxed()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct RateLimiter {
+    store: Option<Arc<dyn RateLimitStore>>,
+    fallback: InMemoryRateLimitStore,
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new<S>(store: S) -> Self
+    where
+        S: RateLimitStore + 'static,
+    {
+        Self {
+            store: Some(Arc::new(store)),
+            fallback: InMemoryRateLimitStore::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    pub async fn hit(&self, key: &str, quota: Quota) -> Decision {
+        let decision = match &self.store {
+            Some(store) => match store.hit(key, quota).await {
+                Ok(decision) => decision,
+                Err(error) => {
+                    tracing::warn!(
+                        key,
+                        error = %error,
+                        "rate limit store is unavailable, falling back"
+                    );
+                    self.fallback.check(key, quota)
+                },
+            },
+            None => self.fallback.check(key, quota),
+        };
+
+        if !decision.allowed {
+            metrics::counter!("rate_limit_rejections_total").increment(1);
+        }
+
+        decision
+    }
+}
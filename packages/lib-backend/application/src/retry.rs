@@ -0,0 +1,190 @@
+use std::{fmt::Display, future::Future, time::Duration};
+
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+pub const DEFAULT_INITIAL_DELAY: Duration = Duration::from_millis(100);
+pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+pub const DEFAULT_MULTIPLIER: u32 = 2;
+
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_delay: DEFAULT_INITIAL_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            multiplier: DEFAULT_MULTIPLIER,
+            jitter: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    #[must_use]
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    #[must_use]
+    pub const fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .initial_delay
+            .saturating_mul(
+                self.multiplier.saturating_pow(attempt.saturating_sub(1)),
+            )
+            .min(self.max_delay);
+
+        if !self.jitter {
+            return delay;
+        }
+
+        let millis = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let half = millis.checked_div(2).unwrap_or_default();
+
+        Duration::from_millis(half.saturating_add(fastrand::u64(0..=half)))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait RetryExt<Fut, T, E>: FnMut() -> Fut + Sized
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    fn retry_traced(
+        self,
+        policy: RetryPolicy,
+        operation: &'static str,
+    ) -> impl Future<Output = Result<T, E>> + Send
+    where
+        Self: Send,
+        Fut: Send,
+        T: Send,
+        E: Display + Send,
+    {
+        self.retry_traced_if(policy, operation, |_: &E| true)
+    }
+
+    fn retry_traced_if<R>(
+        self,
+        policy: RetryPolicy,
+        operation: &'static str,
+        is_retryable: R,
+    ) -> impl Future<Output = Result<T, E>> + Send
+    where
+        Self: Send,
+        Fut: Send,
+        T: Send,
+        E: Display + Send,
+        R: Fn(&E) -> bool + Send;
+}
+
+impl<F, Fut, T, E> RetryExt<Fut, T, E> for F
+where
+    F: FnMut() -> Fut + Sized,
+    Fut: Future<Output = Result<T, E>>,
+{
+    async fn retry_traced_if<R>(
+        mut self,
+        policy: RetryPolicy,
+        operation: &'static str,
+        is_retryable: R,
+    ) -> Result<T, E>
+    where
+        Self: Send,
+        Fut: Send,
+        T: Send,
+        E: Display + Send,
+        R: Fn(&E) -> bool + Send,
+    {
+        let mut attempt: u32 = 1;
+
+        loop {
+            let error = match self().await {
+                Ok(value) => {
+                    if attempt > 1 {
+                        tracing::info!(
+                            operation,
+                            attempt,
+                            "operation succeeded after retrying"
+                        );
+                    }
+
+                    return Ok(value);
+                },
+                Err(error) => error,
+            };
+
+            metrics::counter!("retry_failures_total", "operation" => operation)
+                .increment(1);
+
+            if attempt >= policy.max_attempts || !is_retryable(&error) {
+                tracing::error!(
+                    operation,
+                    attempt,
+                    error = %error,
+                    "operation failed, giving up"
+                );
+                metrics::counter!(
+                    "retry_exhausted_total",
+                    "operation" => operation
+                )
+                .increment(1);
+
+                return Err(error);
+            }
+
+            let delay = policy.backoff(attempt);
+            let delay_ms =
+                u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+
+            tracing::warn!(
+                operation,
+                attempt,
+                delay_ms,
+                error = %error,
+                "operation failed, retrying"
+            );
+            metrics::counter!("retry_attempts_total", "operation" => operation)
+                .increment(1);
+
+            tokio::time::sleep(delay).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+}
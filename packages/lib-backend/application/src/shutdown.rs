@@ -0,0 +1,128 @@
+use std::{sync::Arc, time::Duration};
+
+use futures_util::{FutureExt as _, future::BoxFuture};
+use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
+
+use crate::di::Has;
+
+#[derive(Clone, Default, Debug)]
+pub struct Shutdown {
+    token: CancellationToken,
+}
+
+impl Shutdown {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn child(&self) -> Self {
+        Self {
+            token: self.token.child_token(),
+        }
+    }
+
+    pub fn trigger(&self) {
+        self.token.cancel();
+    }
+
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub async fn triggered(&self) {
+        self.token.cancelled().await;
+    }
+
+    #[must_use]
+    pub fn signal(&self) -> WaitForCancellationFutureOwned {
+        self.token.clone().cancelled_owned()
+    }
+}
+
+pub trait ShutdownHook: Send + Sync {
+    fn shutdown(&self) -> BoxFuture<'_, Result<(), String>>;
+}
+
+impl<F, Fut> ShutdownHook for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    fn shutdown(&self) -> BoxFuture<'_, Result<(), String>> {
+        self().boxed()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ShutdownHooks {
+    hooks: Vec<(&'static str, Arc<dyn ShutdownHook>)>,
+}
+
+impl ShutdownHooks {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn hook<H>(mut self, name: &'static str, hook: H) -> Self
+    where
+        H: ShutdownHook + 'static,
+    {
+        self.hooks.push((name, Arc::new(hook)));
+        self
+    }
+
+    pub async fn run(&self, timeout: Duration) {
+        for (name, hook) in &self.hooks {
+            let result =
+                tokio::time::timeout(timeout, hook.shutdown()).await;
+
+            let outcome = match result {
+                Ok(Ok(())) => {
+                    tracing::info!(hook = name, "shutdown hook completed");
+                    "success"
+                },
+                Ok(Err(error)) => {
+                    tracing::error!(
+                        hook = name,
+                        error = %error,
+                        "shutdown hook failed"
+                    );
+                    "failure"
+                },
+                Err(_) => {
+                    tracing::error!(
+                        hook = name,
+                        timeout_ms = timeout.as_millis(),
+                        "shutdown hook timed out"
+                    );
+                    "timeout"
+                },
+            };
+
+            metrics::counter!(
+                "shutdown_hooks_total",
+                "hook" => *name,
+                "outcome" => outcome
+            )
+            .increment(1);
+        }
+    }
+}
+
+pub trait HasShutdownExt {
+    fn shutdown_hooks(&self) -> &ShutdownHooks;
+}
+
+impl<D> HasShutdownExt for D
+where
+    D: Has<ShutdownHooks>,
+{
+    fn shutdown_hooks(&self) -> &ShutdownHooks {
+        self.get_dependency()
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    any::Any,
+    future::Future,
+    mem,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use entrait::Impl;
+use futures_util::future::BoxFuture;
+use tracing::Instrument as _;
+
+tokio::task_local! {
+    static CURRENT: Arc<dyn Any + Send + Sync>;
+    static AFTER_COMMIT: AfterCommit;
+}
+
+#[derive(Clone, Default)]
+struct AfterCommit(Arc<Mutex<Vec<BoxFuture<'static, ()>>>>);
+
+impl AfterCommit {
+    fn push(&self, hook: BoxFuture<'static, ()>) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(hook);
+    }
+
+    async fn run(self) {
+        let hooks = mem::take(
+            &mut *self.0.lock().unwrap_or_else(PoisonError::into_inner),
+        );
+
+        for hook in hooks {
+            hook.await;
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TransactionError {
+    #[error("failed to begin transaction: {0}")]
+    Begin(String),
+
+    #[error("failed to commit transaction: {0}")]
+    Commit(String),
+
+    #[error("failed to roll back transaction: {0}")]
+    Rollback(String),
+
+    #[error("transaction has already been finished")]
+    Finished,
+}
+
+pub trait Transaction: Clone + Send + Sync + 'static {
+    fn commit(
+        &self,
+    ) -> impl Future<Output = Result<(), TransactionError>> + Send;
+
+    fn rollback(
+        &self,
+    ) -> impl Future<Output = Result<(), TransactionError>> + Send;
+}
+
+pub trait TransactionManager: Send + Sync {
+    type Transaction: Transaction;
+
+    fn begin(
+        &self,
+    ) -> impl Future<Output = Result<Self::Transaction, TransactionError>>
+    + Send;
+}
+
+pub trait HasTransactionManager {
+    type TransactionManager: TransactionManager;
+
+    fn transaction_manager(&self) -> &Self::TransactionManager;
+}
+
+impl<T> HasTransactionManager for Impl<T>
+where
+    T: HasTransactionManager,
+{
+    type TransactionManager = T::TransactionManager;
+
+    fn transaction_manager(&self) -> &Self::TransactionManager {
+        T::transaction_manager(self)
+    }
+}
+
+#[must_use]
+pub fn current<T>() -> Option<T>
+where
+    T: Transaction,
+{
+    CURRENT
+        .try_with(|current| current.downcast_ref::<T>().cloned())
+        .ok()
+        .flatten()
+}
+
+pub async fn after_commit<F>(hook: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    match AFTER_COMMIT.try_with(Clone::clone) {
+        Ok(hooks) => hooks.push(Box::pin(hook)),
+        Err(_) => hook.await,
+    }
+}
+
+pub trait UnitOfWork {
+    fn transaction<F, T, E>(
+        &self,
+        work: F,
+    ) -> impl Future<Output = Result<T, E>> + Send
+    where
+        F: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: From<TransactionError> + Send;
+}
+
+impl<D> UnitOfWork for D
+where
+    D: HasTransactionManager + Sync,
+{
+    async fn transaction<F, T, E>(&self, work: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: From<TransactionError> + Send,
+    {
+        let manager = self.transaction_manager();
+
+        if is_active(manager) {
+            return work.await;
+        }
+
+        let transaction = manager
+            .begin()
+            .instrument(tracing::info_span!("transaction.begin"))
+            .await?;
+
+        let hooks = AfterCommit::default();
+        let result = CURRENT
+            .scope(
+                Arc::new(transaction.clone()),
+                AFTER_COMMIT.scope(hooks.clone(), work),
+            )
+            .await;
+
+        match result {
+            Ok(value) => {
+                transaction
+                    .commit()
+                    .instrument(tracing::info_span!("transaction.commit"))
+                    .await?;
+
+                hooks.run().await;
+
+                Ok(value)
+            },
+            Err(error) => {
+                if let Err(rollback_error) = transaction
+                    .rollback()
+                    .instrument(tracing::info_span!("transaction.rollback"))
+                    .await
+                {
+                    tracing::error!(
+                        error = %rollback_error,
+                        "failed to roll back transaction"
+                    );
+                }
+
+                Err(error)
+            },
+        }
+    }
+}
+
+fn is_active<M>(_manager: &M) -> bool
+where
+    M: TransactionManager,
+{
+    current::<M::Transaction>().is_some()
+}
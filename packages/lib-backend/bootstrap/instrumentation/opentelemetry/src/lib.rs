@@ -1,17 +1,14 @@
-#![feature(try_blocks)]
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
-use std::time::Duration;
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
 
 use metrics_tracing_context::MetricsLayer;
 use opentelemetry::KeyValue;
-use opentelemetry_otlp::{Protocol, WithExportConfig};
+use opentelemetry_otlp::{ExporterBuildError, Protocol, WithExportConfig};
 use opentelemetry_sdk::{
-    Resource, logs::SdkLoggerProvider, metrics::SdkMeterProvider,
-    trace::SdkTracerProvider,
+    Resource, error::OTelSdkError, logs::SdkLoggerProvider,
+    metrics::SdkMeterProvider, trace::SdkTracerProvider,
 };
 use opentelemetry_semantic_conventions::attribute;
 use tracing_subscriber::{
@@ -25,6 +22,51 @@ mod traces;
 
 pub use crate::config::OtelConfig;
 
+#[derive(Debug)]
+pub struct ProviderShutdownError {
+    pub provider: &'static str,
+    pub source: OTelSdkError,
+}
+
+impl Display for ProviderShutdownError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.provider, self.source)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "failed to shut down OpenTelemetry providers: {}",
+    .0
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+)]
+pub struct ShutdownError(pub Vec<ProviderShutdownError>);
+
+#[derive(thiserror::Error, Debug)]
+#[error("failed to build the OpenTelemetry {signal} exporter: {source}")]
+pub struct ExporterError {
+    pub signal: &'static str,
+    #[source]
+    pub source: ExporterBuildError,
+}
+
+impl ExporterError {
+    const fn new(signal: &'static str, source: ExporterBuildError) -> Self {
+        Self {
+            signal,
+            source,
+        }
+    }
+}
+
+pub trait WithShutdownError: From<ShutdownError> {
+    #[must_use]
+    fn with_shutdown_error(self, error: ShutdownError) -> Self;
+}
+
 #[derive(Clone, Debug)]
 pub struct Otel {
     endpoint: Option<String>,
@@ -98,15 +140,30 @@ impl Otel {
         }
     }
 
-    pub async fn wrap<F>(self, future: F)
+    fn providers(&self) -> Result<Providers, ExporterError> {
+        Ok(Providers {
+            service_name: self.service_name.clone(),
+            logger: self.logger_provider()?,
+            meter: self.meter_provider()?,
+            tracer: self.tracer_provider()?,
+        })
+    }
+
+    pub async fn wrap<F, T, E>(self, future: F) -> Result<T, E>
     where
-        F: Future<Output = ()>,
+        F: Future<Output = Result<T, E>>,
+        E: WithShutdownError + From<ExporterError>,
     {
-        let providers = Providers {
-            service_name: self.service_name.clone(),
-            logger: self.logger_provider(),
-            meter: self.meter_provider(),
-            tracer: self.tracer_provider(),
+        let providers = match self.providers() {
+            Ok(providers) => providers,
+            Err(error) => {
+                tracing_subscriber::registry()
+                    .with(stdout::filter_layer())
+                    .with(stdout::fmt_layer())
+                    .init();
+
+                return Err(error.into());
+            },
         };
 
         tracing_subscriber::registry()
@@ -119,15 +176,32 @@ impl Otel {
 
         providers.setup_metrics();
 
-        future.await;
+        let output = future.await;
 
         tracing::info!("Shutting down OpenTelemetry stuff");
 
-        try {
-            providers.tracer.shutdown()?;
-            providers.meter.shutdown()?;
-            providers.logger.shutdown()?;
+        let errors = [
+            ("tracer", providers.tracer.shutdown()),
+            ("meter", providers.meter.shutdown()),
+            ("logger", providers.logger.shutdown()),
+        ]
+        .into_iter()
+        .filter_map(|(provider, result)| {
+            result.err().map(|source| ProviderShutdownError {
+                provider,
+                source,
+            })
+        })
+        .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            return output;
+        }
+
+        let error = ShutdownError(errors);
+        match output {
+            Ok(_) => Err(error.into()),
+            Err(output) => Err(output.with_shutdown_error(error)),
         }
-        .expect("Failed to shut down OpenTelemetry stuff");
     }
 }
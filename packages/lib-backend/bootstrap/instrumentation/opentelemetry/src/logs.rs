@@ -1,19 +1,16 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
 use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
-use opentelemetry_otlp::LogExporter;
+use opentelemetry_otlp::{ExporterBuildError, LogExporter};
 use opentelemetry_sdk::logs::{
     BatchLogProcessor, LogProcessor, SdkLogger, SdkLoggerProvider,
 };
 
-use crate::{Otel, Providers};
+use crate::{ExporterError, Otel, Providers};
 
 impl Otel {
     #[inline]
-    fn log_processor(&self) -> impl LogProcessor + 'static {
+    fn log_processor(
+        &self,
+    ) -> Result<impl LogProcessor + 'static, ExporterBuildError> {
         let builder = LogExporter::builder();
 
         let exporter = self
@@ -21,18 +18,23 @@ impl Otel {
                 feature = "grpc-tonic" => builder.with_tonic(),
                 _ => builder.with_http(),
             })
-            .build()
-            .expect("Failed to build exporter!");
+            .build()?;
 
-        BatchLogProcessor::builder(exporter).build()
+        Ok(BatchLogProcessor::builder(exporter).build())
     }
 
     #[inline]
-    pub(super) fn logger_provider(&self) -> SdkLoggerProvider {
-        SdkLoggerProvider::builder()
+    pub(super) fn logger_provider(
+        &self,
+    ) -> Result<SdkLoggerProvider, ExporterError> {
+        let processor = self
+            .log_processor()
+            .map_err(|source| ExporterError::new("logs", source))?;
+
+        Ok(SdkLoggerProvider::builder()
             .with_resource(self.resource.clone())
-            .with_log_processor(self.log_processor())
-            .build()
+            .with_log_processor(processor)
+            .build())
     }
 }
 
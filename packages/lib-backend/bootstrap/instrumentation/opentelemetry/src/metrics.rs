@@ -1,14 +1,9 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
 use std::time::Duration;
 
 use metrics_exporter_otel::OpenTelemetryRecorder;
 use metrics_process::Collector;
 use opentelemetry::{global, metrics::MeterProvider as _};
-use opentelemetry_otlp::MetricExporter;
+use opentelemetry_otlp::{ExporterBuildError, MetricExporter};
 use opentelemetry_sdk::{
     metrics::{
         SdkMeterProvider, periodic_reader_with_async_runtime::PeriodicReader,
@@ -16,14 +11,16 @@ use opentelemetry_sdk::{
     },
     runtime,
 };
-use tap::Tap as _;
+use tap::{Pipe as _, Tap as _};
 
-use crate::{Otel, Providers};
+use crate::{ExporterError, Otel, Providers};
 
 const METRIC_SCRAPE_INTERVAL: Duration = Duration::from_secs(1);
 
 impl Otel {
-    pub(super) fn periodic_reader(&self) -> impl MetricReader + 'static {
+    pub(super) fn periodic_reader(
+        &self,
+    ) -> Result<impl MetricReader + 'static, ExporterBuildError> {
         let builder = MetricExporter::builder();
 
         let exporter = self
@@ -31,23 +28,29 @@ impl Otel {
                 feature = "grpc-tonic" => builder.with_tonic(),
                 _ => builder.with_http(),
             })
-            .build()
-            .expect("Failed to build exporter!");
+            .build()?;
 
-        PeriodicReader::builder(exporter, runtime::Tokio)
+        Ok(PeriodicReader::builder(exporter, runtime::Tokio)
             .with_interval(METRIC_SCRAPE_INTERVAL.saturating_mul(10))
-            .build()
+            .build())
     }
 
     #[inline]
-    pub(super) fn meter_provider(&self) -> SdkMeterProvider {
+    pub(super) fn meter_provider(
+        &self,
+    ) -> Result<SdkMeterProvider, ExporterError> {
+        let reader = self
+            .periodic_reader()
+            .map_err(|source| ExporterError::new("metrics", source))?;
+
         SdkMeterProvider::builder()
             .with_resource(self.resource.clone())
-            .with_reader(self.periodic_reader())
+            .with_reader(reader)
             .build()
             .tap(|provider| {
                 global::set_meter_provider(provider.clone());
             })
+            .pipe(Ok)
     }
 }
 
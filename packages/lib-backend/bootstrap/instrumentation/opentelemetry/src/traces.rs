@@ -1,23 +1,20 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
 use opentelemetry::{global, trace::TracerProvider as _};
-use opentelemetry_otlp::SpanExporter;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter};
 use opentelemetry_sdk::trace::{
     BatchSpanProcessor, SdkTracerProvider, SpanProcessor, Tracer,
 };
-use tap::Tap as _;
+use tap::{Pipe as _, Tap as _};
 use tracing::Subscriber;
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::registry::LookupSpan;
 
-use crate::{Otel, Providers};
+use crate::{ExporterError, Otel, Providers};
 
 impl Otel {
     #[inline]
-    fn span_processor(&self) -> impl SpanProcessor + 'static {
+    fn span_processor(
+        &self,
+    ) -> Result<impl SpanProcessor + 'static, ExporterBuildError> {
         let builder = SpanExporter::builder();
 
         let exporter = self
@@ -25,21 +22,27 @@ impl Otel {
                 feature = "grpc-tonic" => builder.with_tonic(),
                 _ => builder.with_http(),
             })
-            .build()
-            .expect("Failed to build exporter!");
+            .build()?;
 
-        BatchSpanProcessor::builder(exporter).build()
+        Ok(BatchSpanProcessor::builder(exporter).build())
     }
 
     #[inline]
-    pub(super) fn tracer_provider(&self) -> SdkTracerProvider {
+    pub(super) fn tracer_provider(
+        &self,
+    ) -> Result<SdkTracerProvider, ExporterError> {
+        let processor = self
+            .span_processor()
+            .map_err(|source| ExporterError::new("trace", source))?;
+
         SdkTracerProvider::builder()
             .with_resource(self.resource.clone())
-            .with_span_processor(self.span_processor())
+            .with_span_processor(processor)
             .build()
             .tap(|provider| {
                 global::set_tracer_provider(provider.clone());
             })
+            .pipe(Ok)
     }
 }
 
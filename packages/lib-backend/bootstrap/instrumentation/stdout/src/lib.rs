@@ -74,14 +74,14 @@ pub fn fmt_layer<S>() -> Layer<S, format::Pretty, Format<format::Pretty>> {
         .with_target(true)
 }
 
-pub async fn wrap<F>(future: F)
+pub async fn wrap<F, T>(future: F) -> T
 where
-    F: Future<Output = ()>,
+    F: Future<Output = T>,
 {
     tracing_subscriber::registry()
         .with(filter_layer())
         .with(fmt_layer())
         .init();
 
-    future.await;
+    future.await
 }
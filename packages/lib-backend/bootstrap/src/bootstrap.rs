@@ -1,30 +1,62 @@
 use async_trait::async_trait;
 
+use crate::shutdown::Shutdown;
+
 #[async_trait]
 pub trait Bootstrapper {
     type Config: fromenv::__private::FromEnv;
     type Modules: Send + Sync;
+    type Error: std::error::Error + Send + Sync + 'static;
 
     async fn bootstrap(
         config: &Self::Config,
         deps: &::entrait::Impl<Self::Modules>,
-    );
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error>;
 }
 
 #[macro_export]
 macro_rules! bootstrap {
-    ([], $_modules_fut: expr) => {
+    ([], $_modules_fut: expr $(, shutdown: $_shutdown_config: expr)?) => {
        compile_error!("`bootstrap!` can't be called with empty bootstrapper array!")
     };
-    ([$($bootstrapper: tt($config_field: expr)),*], $modules_fut: expr) => {
+    (
+        [$($bootstrapper: tt($config_field: expr)),*],
+        $modules_fut: expr
+        $(, shutdown: $shutdown_config: expr)?
+    ) => {
         async {
-            use $crate::Bootstrapper as _;
+            use $crate::{
+                Bootstrapper as _,
+                application::shutdown::HasShutdownExt as _,
+            };
+
+            if let Some(sources) = $crate::config::sources() {
+                sources.report();
+            }
 
+            let modules = match $modules_fut.await {
+                Ok(modules) => $crate::entrait::Impl::new(modules),
+                Err(error) => {
+                    return Err($crate::BootstrapError::modules(error));
+                },
+            };
+            let shutdown = $crate::shutdown::Shutdown::new();
 
-            let modules = $crate::entrait::Impl::new($modules_fut.await);
-            tokio::join!(
-                $($bootstrapper::bootstrap($config_field, &modules)),*
-            );
+            $crate::shutdown::Coordinator::new(shutdown.clone())
+                $(.config($shutdown_config))?
+                $(
+                    .bootstrapper(
+                        stringify!($bootstrapper),
+                        $bootstrapper::bootstrap(
+                            $config_field,
+                            &modules,
+                            shutdown.clone(),
+                        ),
+                    )
+                )*
+                .run(modules.shutdown_hooks())
+                .await
         }
     };
 }
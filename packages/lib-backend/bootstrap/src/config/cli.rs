@@ -0,0 +1,52 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use super::ConfigLayerError;
+
+#[derive(Default, Debug)]
+pub struct CliOverrides {
+    pub config_file: Option<PathBuf>,
+    pub values: BTreeMap<String, String>,
+}
+
+impl CliOverrides {
+    pub fn parse<I>(args: I) -> Result<Self, ConfigLayerError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut overrides = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(path) = arg.strip_prefix("--config=") {
+                overrides.config_file = Some(PathBuf::from(path));
+            } else if let Some(pair) = arg.strip_prefix("--set=") {
+                overrides.set(pair)?;
+            } else if arg == "--config" {
+                let path = args
+                    .next()
+                    .ok_or(ConfigLayerError::MissingValue(arg))?;
+                overrides.config_file = Some(PathBuf::from(path));
+            } else if arg == "--set" {
+                let pair =
+                    args.next().ok_or(ConfigLayerError::MissingValue(arg))?;
+                overrides.set(&pair)?;
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    fn set(&mut self, pair: &str) -> Result<(), ConfigLayerError> {
+        let (key, value) = pair
+            .split_once('=')
+            .filter(|(key, _)| !key.trim().is_empty())
+            .ok_or_else(|| {
+                ConfigLayerError::InvalidOverride(pair.to_owned())
+            })?;
+
+        self.values
+            .insert(key.trim().to_uppercase(), value.to_owned());
+
+        Ok(())
+    }
+}
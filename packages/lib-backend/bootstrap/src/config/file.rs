@@ -0,0 +1,113 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use super::ConfigLayerError;
+
+pub fn load(
+    path: &Path,
+) -> Result<BTreeMap<String, String>, ConfigLayerError> {
+    let contents =
+        fs::read_to_string(path).map_err(|source| ConfigLayerError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    // Parser messages quote the offending source line, which may hold a
+    // secret, so only the position is reported.
+    let parse_error = |format: &str, position: Option<(usize, usize)>| {
+        let message = position.map_or_else(
+            || format!("invalid {format}"),
+            |(line, column)| {
+                format!("invalid {format} at line {line}, column {column}")
+            },
+        );
+
+        ConfigLayerError::Parse {
+            path: path.to_path_buf(),
+            message,
+        }
+    };
+
+    let value = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => {
+            toml::from_str::<Value>(&contents).map_err(|error| {
+                let position = error
+                    .span()
+                    .and_then(|span| line_column(&contents, span.start));
+                parse_error("TOML", position)
+            })?
+        },
+        Some("yaml" | "yml") => serde_yaml::from_str::<Value>(&contents)
+            .map_err(|error| {
+                let position = error
+                    .location()
+                    .map(|location| (location.line(), location.column()));
+                parse_error("YAML", position)
+            })?,
+        Some("json") => {
+            serde_json::from_str::<Value>(&contents).map_err(|error| {
+                parse_error("JSON", Some((error.line(), error.column())))
+            })?
+        },
+        _ => return Err(ConfigLayerError::Format(PathBuf::from(path))),
+    };
+
+    let mut values = BTreeMap::new();
+    flatten(None, value, &mut values);
+
+    Ok(values)
+}
+
+fn line_column(contents: &str, offset: usize) -> Option<(usize, usize)> {
+    let before = contents.get(..offset)?;
+    let line = before.matches('\n').count().saturating_add(1);
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |line| line.chars().count())
+        .saturating_add(1);
+
+    Some((line, column))
+}
+
+fn flatten(
+    prefix: Option<&str>,
+    value: Value,
+    out: &mut BTreeMap<String, String>,
+) {
+    let value = match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = key.to_uppercase().replace(['-', '.'], "_");
+                let key = match prefix {
+                    Some(prefix) => format!("{prefix}_{key}"),
+                    None => key,
+                };
+
+                flatten(Some(&key), value, out);
+            }
+
+            return;
+        },
+        Value::Null => return,
+        Value::String(value) => value,
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(item) => item,
+                item => item.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(","),
+        value @ (Value::Bool(_) | Value::Number(_)) => value.to_string(),
+    };
+
+    if let Some(key) = prefix {
+        out.insert(key.to_owned(), value);
+    }
+}
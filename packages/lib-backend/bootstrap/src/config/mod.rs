@@ -0,0 +1,342 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    ffi::OsString,
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use fromenv::__private::{FromEnv, FromEnvBuilder};
+
+pub use self::{
+    cli::CliOverrides,
+    secret::{SecretError, SecretProvider, SecretProviders, SecretString},
+    validate::{ConfigIssue, ConfigReport, Validate, Validator, is_secret_key},
+};
+
+mod cli;
+mod file;
+pub mod secret;
+mod validate;
+
+pub const CONFIG_FILE_VAR: &str = "CONFIG_FILE";
+
+static SOURCES: OnceLock<ConfigSources> = OnceLock::new();
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConfigLayerError {
+    #[error("failed to read config file {}: {source}", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to parse config file {}: {message}", .path.display())]
+    Parse { path: PathBuf, message: String },
+
+    #[error("unsupported config file format: {}", .0.display())]
+    Format(PathBuf),
+
+    #[error("command-line argument `{0}` requires a value")]
+    MissingValue(String),
+
+    #[error("invalid override `{0}`, expected `KEY=VALUE`")]
+    InvalidOverride(String),
+
+    #[error(transparent)]
+    Secret(#[from] SecretError),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ConfigSource {
+    Default,
+    File(PathBuf),
+    Dotenv,
+    Secret(&'static str),
+    Env,
+    Cli,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::File(path) => write!(f, "file:{}", path.display()),
+            Self::Dotenv => write!(f, ".env"),
+            Self::Secret(provider) => write!(f, "secret:{provider}"),
+            Self::Env => write!(f, "env"),
+            Self::Cli => write!(f, "cli"),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConfigSources {
+    sources: BTreeMap<String, ConfigSource>,
+    unknown: BTreeSet<String>,
+}
+
+impl ConfigSources {
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&ConfigSource> {
+        self.sources.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ConfigSource)> {
+        self.sources
+            .iter()
+            .map(|(key, source)| (key.as_str(), source))
+    }
+
+    pub fn unknown(&self) -> impl Iterator<Item = &str> {
+        self.unknown.iter().map(String::as_str)
+    }
+
+    pub fn report(&self) {
+        for (key, source) in self.iter() {
+            tracing::info!(key, source = %source, "configuration value");
+        }
+
+        for key in self.unknown() {
+            tracing::warn!(key, "unknown configuration key ignored");
+        }
+    }
+}
+
+#[must_use]
+pub fn sources() -> Option<&'static ConfigSources> {
+    SOURCES.get()
+}
+
+/// Layered values (config file and `--set` overrides) only reach the
+/// `FromEnv` builder through the process environment. They are written there
+/// for the duration of the build and restored right after it. Resolved
+/// secrets are handed to the builder by reference and never written, so
+/// secret providers only serve keys named like secrets (see
+/// [`is_secret_key`]), which must be [`SecretString`] fields.
+///
+/// # Safety
+///
+/// Loading mutates the process environment. It must run before any other
+/// thread is spawned, e.g. at the top of `main` and before starting the
+/// async runtime.
+pub trait ConfigExt: FromEnv {
+    type Target;
+
+    /// # Safety
+    ///
+    /// See [`ConfigExt`].
+    unsafe fn try_load_with(
+        secrets: &SecretProviders,
+    ) -> Result<Self::Target, ConfigReport>;
+
+    /// # Safety
+    ///
+    /// See [`ConfigExt`].
+    unsafe fn try_load() -> Result<Self::Target, ConfigReport> {
+        match SecretProviders::from_env() {
+            // SAFETY: forwarded from the caller.
+            Ok(secrets) => unsafe { Self::try_load_with(&secrets) },
+            Err(error) => {
+                let mut report = ConfigReport::default();
+                report.push(None::<String>, error);
+                Err(report)
+            },
+        }
+    }
+
+    /// # Safety
+    ///
+    /// See [`ConfigExt`].
+    unsafe fn load() -> Self::Target {
+        // SAFETY: forwarded from the caller.
+        unsafe { Self::try_load() }.unwrap_or_else(|report| panic!("{report}"))
+    }
+}
+
+impl<C> ConfigExt for C
+where
+    C: FromEnv,
+    <C::FromEnvBuilder as FromEnvBuilder>::Target: Validate,
+{
+    type Target = <C::FromEnvBuilder as FromEnvBuilder>::Target;
+
+    unsafe fn try_load_with(
+        secrets: &SecretProviders,
+    ) -> Result<Self::Target, ConfigReport> {
+        let mut report = ConfigReport::default();
+
+        let resolved = resolve_layers::<C>(env::args().skip(1), secrets);
+        let layered = match resolved {
+            Ok((sources, layered)) => {
+                SOURCES.get_or_init(|| sources);
+                layered
+            },
+            Err(error) => {
+                report.push(None::<String>, error);
+                Vec::new()
+            },
+        };
+
+        // SAFETY: the caller guarantees no other thread is running.
+        let built =
+            unsafe { with_layers(layered, || Self::from_env().finalize()) };
+        secret::clear_references();
+
+        let config = match built {
+            Ok(config) if report.is_empty() => config,
+            Ok(_) => return Err(report),
+            Err(error) => {
+                report.extend_lines(error);
+                return Err(report);
+            },
+        };
+
+        let mut validator = Validator::default();
+        config.validate(&mut validator);
+
+        let issues = validator.finish();
+        if issues.is_empty() {
+            Ok(config)
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+fn schema_keys<C>() -> BTreeSet<String>
+where
+    C: FromEnv,
+{
+    let mut requirements = String::new();
+    C::requirements(&mut requirements);
+
+    requirements
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().to_owned())
+        .filter(|key| !key.is_empty())
+        .collect()
+}
+
+type Layered = Vec<(String, String)>;
+
+fn resolve_layers<C>(
+    args: impl IntoIterator<Item = String>,
+    secrets: &SecretProviders,
+) -> Result<(ConfigSources, Layered), ConfigLayerError>
+where
+    C: FromEnv,
+{
+    let keys = schema_keys::<C>();
+    let overrides = CliOverrides::parse(args)?;
+
+    let from_env = keys
+        .iter()
+        .filter(|key| env::var_os(key).is_some())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    #[cfg(debug_assertions)]
+    match dotenvy::dotenv() {
+        Ok(_) => {
+            tracing::debug!("Successfully loaded .env");
+        },
+        Err(_) => {
+            tracing::debug!("Failed to load .env");
+        },
+    };
+
+    let file = overrides
+        .config_file
+        .clone()
+        .or_else(|| env::var_os(CONFIG_FILE_VAR).map(PathBuf::from))
+        .map(|path| file::load(&path).map(|values| (path, values)))
+        .transpose()?;
+
+    let mut sources = ConfigSources::default();
+    let mut layered = Vec::new();
+
+    for key in &keys {
+        let file_value = file
+            .as_ref()
+            .and_then(|(path, values)| Some((path, values.get(key)?)));
+
+        secret::FileSecretProvider::ensure_single_source(key)?;
+
+        let source = if let Some(value) = overrides.values.get(key) {
+            layered.push((key.clone(), value.clone()));
+            ConfigSource::Cli
+        } else if from_env.contains(key) {
+            ConfigSource::Env
+        } else if env::var_os(key).is_some() {
+            ConfigSource::Dotenv
+        } else if let Some((provider, secret)) = secrets.resolve(key)? {
+            if !is_secret_key(key) {
+                return Err(SecretError::NotSecret {
+                    key: key.clone(),
+                    provider,
+                }
+                .into());
+            }
+
+            layered.push((key.clone(), secret::reference(key, secret)));
+            ConfigSource::Secret(provider)
+        } else if let Some((path, value)) = file_value {
+            layered.push((key.clone(), value.clone()));
+            ConfigSource::File(path.clone())
+        } else {
+            ConfigSource::Default
+        };
+
+        sources.sources.insert(key.clone(), source);
+    }
+
+    let provided = file
+        .iter()
+        .flat_map(|(_, values)| values.keys())
+        .chain(overrides.values.keys());
+    sources.unknown = provided
+        .filter(|key| !keys.contains(*key))
+        .cloned()
+        .collect();
+
+    Ok((sources, layered))
+}
+
+/// # Safety
+///
+/// Mutates the process environment, see [`ConfigExt`].
+unsafe fn with_layers<F, T>(layered: Layered, build: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = layered
+        .into_iter()
+        .map(|(key, value)| {
+            let previous = env::var_os(&key);
+            // SAFETY: forwarded from the caller.
+            unsafe { env::set_var(&key, value) };
+            (key, previous)
+        })
+        .collect::<Vec<(String, Option<OsString>)>>();
+
+    let built = build();
+
+    for (key, previous) in previous.into_iter().rev() {
+        // SAFETY: forwarded from the caller.
+        unsafe {
+            match previous {
+                Some(value) => env::set_var(key, value),
+                None => env::remove_var(key),
+            }
+        }
+    }
+
+    built
+}
@@ -0,0 +1,34 @@
+use std::env;
+
+use redact::Secret;
+
+use super::{SecretProvider, SecretResult};
+
+#[derive(Clone, Default, Debug)]
+pub struct EnvSecretProvider {
+    prefix: String,
+}
+
+impl EnvSecretProvider {
+    #[must_use]
+    pub fn with_prefix<P>(prefix: P) -> Self
+    where
+        P: Into<String>,
+    {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SecretProvider for EnvSecretProvider {
+    fn name(&self) -> &'static str {
+        "env"
+    }
+
+    fn get(&self, key: &str) -> SecretResult<Option<Secret<String>>> {
+        Ok(env::var(format!("{}{key}", self.prefix))
+            .ok()
+            .map(Secret::new))
+    }
+}
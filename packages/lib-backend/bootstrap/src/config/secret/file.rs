@@ -0,0 +1,40 @@
+use std::{env, fs, path::PathBuf};
+
+use redact::Secret;
+
+use super::{FILE_SUFFIX, SecretError, SecretProvider, SecretResult};
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FileSecretProvider;
+
+impl FileSecretProvider {
+    pub fn ensure_single_source(key: &str) -> SecretResult<()> {
+        if env::var_os(key).is_some()
+            && env::var_os(format!("{key}{FILE_SUFFIX}")).is_some()
+        {
+            return Err(SecretError::Conflict(key.to_owned()));
+        }
+
+        Ok(())
+    }
+}
+
+impl SecretProvider for FileSecretProvider {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn get(&self, key: &str) -> SecretResult<Option<Secret<String>>> {
+        let Some(path) = env::var_os(format!("{key}{FILE_SUFFIX}")) else {
+            return Ok(None);
+        };
+        let path = PathBuf::from(path);
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|source| SecretError::Read { path, source })?;
+
+        Ok(Some(Secret::new(
+            contents.trim_end_matches(['\r', '\n']).to_owned(),
+        )))
+    }
+}
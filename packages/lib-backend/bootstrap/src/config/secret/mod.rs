@@ -0,0 +1,186 @@
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    fmt::{self, Debug},
+    io,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+};
+
+use redact::Secret;
+
+pub use self::{
+    env::EnvSecretProvider, file::FileSecretProvider,
+    store::{EncryptedFileSecretProvider, STORE_KEY_VAR, STORE_PATH_VAR},
+};
+
+mod env;
+mod file;
+mod store;
+
+pub const FILE_SUFFIX: &str = "_FILE";
+
+const REFERENCE_PREFIX: &str = "secret-ref:";
+
+static RESOLVED: Mutex<BTreeMap<String, Secret<String>>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(thiserror::Error, Debug)]
+pub enum SecretError {
+    #[error("failed to read secret file {}: {source}", .path.display())]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to write secret file {}: {source}", .path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("malformed secret store {}: {message}", .path.display())]
+    Store { path: PathBuf, message: String },
+
+    #[error("invalid secret store key: {0}")]
+    Key(String),
+
+    #[error("failed to decrypt secret `{0}`")]
+    Decrypt(String),
+
+    #[error("failed to encrypt secret `{0}`")]
+    Encrypt(String),
+
+    #[error("both `{0}` and `{0}{FILE_SUFFIX}` are set, use only one")]
+    Conflict(String),
+
+    #[error(
+        "`{key}` is not a secret field and can't be read from the \
+         `{provider}` secret provider, set `{key}` directly"
+    )]
+    NotSecret {
+        key: String,
+        provider: &'static str,
+    },
+}
+
+pub type SecretResult<T> = Result<T, SecretError>;
+
+pub trait SecretProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn get(&self, key: &str) -> SecretResult<Option<Secret<String>>>;
+}
+
+#[derive(Default)]
+pub struct SecretProviders {
+    providers: Vec<Box<dyn SecretProvider>>,
+}
+
+impl SecretProviders {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_env() -> SecretResult<Self> {
+        let mut providers = Self::new().provider(FileSecretProvider);
+
+        if let Some(store) = EncryptedFileSecretProvider::from_env()? {
+            providers = providers.provider(store);
+        }
+
+        Ok(providers)
+    }
+
+    #[must_use]
+    pub fn provider<P>(mut self, provider: P) -> Self
+    where
+        P: SecretProvider + 'static,
+    {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    pub fn resolve(
+        &self,
+        key: &str,
+    ) -> SecretResult<Option<(&'static str, Secret<String>)>> {
+        for provider in &self.providers {
+            if let Some(secret) = provider.get(key)? {
+                return Ok(Some((provider.name(), secret)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+// Resolved secrets never enter the process environment: the `FromEnv`
+// builder only sees a reference that `SecretString` swaps for the value.
+pub(super) fn reference(key: &str, secret: Secret<String>) -> String {
+    RESOLVED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(key.to_owned(), secret);
+
+    format!("{REFERENCE_PREFIX}{key}")
+}
+
+pub(super) fn clear_references() {
+    RESOLVED.lock().unwrap_or_else(PoisonError::into_inner).clear();
+}
+
+#[derive(Clone)]
+pub struct SecretString(Secret<String>);
+
+impl SecretString {
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        self.0.expose_secret()
+    }
+
+    #[must_use]
+    pub const fn as_secret(&self) -> &Secret<String> {
+        &self.0
+    }
+}
+
+impl Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for SecretString {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let resolved = value.strip_prefix(REFERENCE_PREFIX).and_then(|key| {
+            RESOLVED
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(key)
+                .cloned()
+        });
+
+        Ok(Self(
+            resolved.unwrap_or_else(|| Secret::new(value.to_owned())),
+        ))
+    }
+}
+
+impl From<Secret<String>> for SecretString {
+    fn from(secret: Secret<String>) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<SecretString> for Secret<String> {
+    fn from(secret: SecretString) -> Self {
+        secret.0
+    }
+}
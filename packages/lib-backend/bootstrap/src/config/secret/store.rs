@@ -0,0 +1,175 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use chacha20poly1305::{
+    AeadCore as _, ChaCha20Poly1305, Key, KeyInit as _, Nonce,
+    aead::{Aead as _, OsRng, Payload},
+};
+use redact::Secret;
+
+use super::{
+    EnvSecretProvider, FileSecretProvider, SecretError, SecretProvider,
+    SecretProviders, SecretResult,
+};
+
+pub const STORE_PATH_VAR: &str = "SECRETS_STORE_PATH";
+pub const STORE_KEY_VAR: &str = "SECRETS_STORE_KEY";
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+pub struct EncryptedFileSecretProvider {
+    path: PathBuf,
+    cipher: ChaCha20Poly1305,
+    entries: BTreeMap<String, String>,
+}
+
+impl EncryptedFileSecretProvider {
+    pub fn from_env() -> SecretResult<Option<Self>> {
+        let Some(path) = env::var_os(STORE_PATH_VAR) else {
+            return Ok(None);
+        };
+
+        FileSecretProvider::ensure_single_source(STORE_KEY_VAR)?;
+
+        let (_, key) = SecretProviders::new()
+            .provider(EnvSecretProvider::default())
+            .provider(FileSecretProvider)
+            .resolve(STORE_KEY_VAR)?
+            .ok_or_else(|| {
+                SecretError::Key(format!("{STORE_KEY_VAR} is not set"))
+            })?;
+
+        Self::open(path, &key).map(Some)
+    }
+
+    pub fn create<P>(path: P, key: &Secret<String>) -> SecretResult<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        Ok(Self {
+            path: path.into(),
+            cipher: cipher(key)?,
+            entries: BTreeMap::new(),
+        })
+    }
+
+    pub fn open<P>(path: P, key: &Secret<String>) -> SecretResult<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+
+        let contents =
+            fs::read_to_string(&path).map_err(|source| SecretError::Read {
+                path: path.clone(),
+                source,
+            })?;
+
+        let entries = serde_json::from_str(&contents).map_err(|error| {
+            SecretError::Store {
+                path: path.clone(),
+                message: error.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            path,
+            cipher: cipher(key)?,
+            entries,
+        })
+    }
+
+    #[must_use]
+    pub fn generate_key() -> Secret<String> {
+        Secret::new(STANDARD.encode(ChaCha20Poly1305::generate_key(&mut OsRng)))
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn insert(
+        &mut self,
+        name: &str,
+        value: &Secret<String>,
+    ) -> SecretResult<()> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, Payload {
+                msg: value.expose_secret().as_bytes(),
+                aad: name.as_bytes(),
+            })
+            .map_err(|_| SecretError::Encrypt(name.to_owned()))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend(ciphertext);
+
+        self.entries.insert(name.to_owned(), STANDARD.encode(sealed));
+
+        Ok(())
+    }
+
+    pub fn save(&self) -> SecretResult<()> {
+        let contents =
+            serde_json::to_string_pretty(&self.entries).map_err(|error| {
+                SecretError::Store {
+                    path: self.path.clone(),
+                    message: error.to_string(),
+                }
+            })?;
+
+        fs::write(&self.path, contents).map_err(|source| SecretError::Write {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+impl SecretProvider for EncryptedFileSecretProvider {
+    fn name(&self) -> &'static str {
+        "store"
+    }
+
+    fn get(&self, key: &str) -> SecretResult<Option<Secret<String>>> {
+        let Some(sealed) = self.entries.get(key) else {
+            return Ok(None);
+        };
+        let decrypt_error = || SecretError::Decrypt(key.to_owned());
+
+        let sealed = STANDARD.decode(sealed).map_err(|_| decrypt_error())?;
+        let (nonce, ciphertext) = sealed
+            .split_first_chunk::<NONCE_LEN>()
+            .ok_or_else(decrypt_error)?;
+
+        let plaintext = self
+            .cipher
+            .decrypt(&Nonce::from(*nonce), Payload {
+                msg: ciphertext,
+                aad: key.as_bytes(),
+            })
+            .map_err(|_| decrypt_error())?;
+
+        String::from_utf8(plaintext)
+            .map(|value| Some(Secret::new(value)))
+            .map_err(|_| decrypt_error())
+    }
+}
+
+fn cipher(key: &Secret<String>) -> SecretResult<ChaCha20Poly1305> {
+    let key = STANDARD
+        .decode(key.expose_secret().trim())
+        .map_err(|_| SecretError::Key("expected base64".to_owned()))?;
+
+    let key = <[u8; KEY_LEN]>::try_from(key.as_slice()).map_err(|_| {
+        SecretError::Key(format!("expected {KEY_LEN} bytes"))
+    })?;
+
+    Ok(ChaCha20Poly1305::new(&Key::from(key)))
+}
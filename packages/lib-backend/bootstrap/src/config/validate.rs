@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr as _,
+};
+
+use croner::Cron;
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+use instrumentation::opentelemetry::OtelConfig;
+
+use crate::{shutdown::ShutdownConfig, startup::StartupConfig};
+
+const REDACTED_DETAILS: &str = "invalid or missing value (details redacted)";
+const SECRET_MARKERS: [&str; 5] =
+    ["SECRET", "PASSWORD", "TOKEN", "PRIVATE_KEY", "API_KEY"];
+
+pub trait Validate {
+    fn validate(&self, _validator: &mut Validator) {}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfigIssue {
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.key {
+            Some(key) => write!(f, "{key}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConfigReport {
+    issues: Vec<ConfigIssue>,
+}
+
+impl ConfigReport {
+    #[must_use]
+    pub fn issues(&self) -> &[ConfigIssue] {
+        &self.issues
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub(super) fn push<K, M>(&mut self, key: Option<K>, message: M)
+    where
+        K: Into<String>,
+        M: Display,
+    {
+        self.issues.push(ConfigIssue {
+            key: key.map(Into::into),
+            message: message.to_string(),
+        });
+    }
+
+    pub(super) fn extend_lines<M>(&mut self, message: M)
+    where
+        M: Display,
+    {
+        message
+            .to_string()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .for_each(|line| match secret_key(line) {
+                Some(key) => self.push(Some(key), REDACTED_DETAILS),
+                None => self.push(None::<String>, line),
+            });
+    }
+}
+
+impl Display for ConfigReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid configuration ({} problem(s)):",
+            self.issues.len()
+        )?;
+
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigReport {}
+
+#[derive(Default)]
+pub struct Validator {
+    report: ConfigReport,
+}
+
+impl Validator {
+    pub fn nested<V>(&mut self, config: &V) -> &mut Self
+    where
+        V: Validate,
+    {
+        config.validate(self);
+        self
+    }
+
+    pub fn check<M>(
+        &mut self,
+        key: &str,
+        valid: bool,
+        message: M,
+    ) -> &mut Self
+    where
+        M: Display,
+    {
+        if !valid {
+            self.report.push(Some(key), message);
+        }
+        self
+    }
+
+    pub fn port(&mut self, key: &str, port: u16) -> &mut Self {
+        self.check(key, port != 0, "must be a port between 1 and 65535")
+    }
+
+    pub fn positive(&mut self, key: &str, value: u64) -> &mut Self {
+        self.check(key, value > 0, "must be greater than zero")
+    }
+
+    pub fn non_empty(&mut self, key: &str, value: &str) -> &mut Self {
+        self.check(key, !value.trim().is_empty(), "must not be empty")
+    }
+
+    pub fn url(
+        &mut self,
+        key: &str,
+        value: &str,
+        schemes: &[&str],
+    ) -> &mut Self {
+        let valid = value
+            .split_once("://")
+            .is_some_and(|(scheme, rest)| {
+                schemes.contains(&scheme)
+                    && rest
+                        .split(['/', '?', '#'])
+                        .next()
+                        .is_some_and(|authority| !authority.is_empty())
+                    && !rest.contains(char::is_whitespace)
+            });
+
+        self.check(
+            key,
+            valid,
+            format_args!(
+                "must be a URL with one of the schemes: {}",
+                schemes.join(", ")
+            ),
+        )
+    }
+
+    pub fn cron(&mut self, key: &str, expression: &str) -> &mut Self {
+        match Cron::from_str(expression) {
+            Ok(_) => self,
+            Err(error) => self.check(
+                key,
+                false,
+                format_args!("invalid cron expression: {error}"),
+            ),
+        }
+    }
+
+    pub fn secret(
+        &mut self,
+        key: &str,
+        value: &str,
+        min_len: usize,
+    ) -> &mut Self {
+        self.check(
+            key,
+            value.len() >= min_len,
+            format_args!("must be at least {min_len} bytes long"),
+        )
+    }
+
+    pub(super) fn finish(self) -> ConfigReport {
+        self.report
+    }
+}
+
+impl Validate for ShutdownConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .positive("SHUTDOWN_GRACE_PERIOD_SECS", self.grace_period_secs)
+            .positive("SHUTDOWN_HOOK_TIMEOUT_SECS", self.hook_timeout_secs);
+    }
+}
+
+impl Validate for StartupConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .positive("STARTUP_DEADLINE_SECS", self.deadline_secs)
+            .positive("STARTUP_PROBE_TIMEOUT_MS", self.probe_timeout_ms);
+    }
+}
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+impl Validate for OtelConfig {
+    fn validate(&self, validator: &mut Validator) {
+        validator
+            .url("OTEL_ENDPOINT", &self.endpoint, &["http", "https"])
+            .non_empty("OTEL_SERVICE_NAME", &self.service_name);
+    }
+}
+
+#[must_use]
+pub fn is_secret_key(key: &str) -> bool {
+    let key = key.to_uppercase();
+    SECRET_MARKERS.iter().any(|marker| key.contains(marker))
+}
+
+// Builder errors may echo the raw value, so for secret fields only the
+// field name is kept.
+fn secret_key(line: &str) -> Option<&str> {
+    line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .find(|token| !token.is_empty() && is_secret_key(token))
+}
@@ -0,0 +1,127 @@
+use std::fmt::{self, Display};
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+use instrumentation::opentelemetry::{
+    ExporterError, ShutdownError, WithShutdownError,
+};
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum BootstrapperError {
+    #[error(transparent)]
+    Failed(BoxError),
+
+    #[error("panicked: {0}")]
+    Panicked(String),
+
+    #[error("exited before shutdown was requested")]
+    ExitedEarly,
+
+    #[error(transparent)]
+    Instrumentation(BoxError),
+}
+
+impl BootstrapperError {
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Failed(_) => "failed",
+            Self::Panicked(_) => "panicked",
+            Self::ExitedEarly => "exited_early",
+            Self::Instrumentation(_) => "instrumentation",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BootstrapperFailure {
+    pub name: &'static str,
+    pub error: BootstrapperError,
+}
+
+impl Display for BootstrapperFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.error)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BootstrapError {
+    #[error("failed to initialize modules: {0}")]
+    Modules(#[source] BoxError),
+
+    #[error("{} bootstrapper(s) failed", .0.len())]
+    Bootstrappers(Vec<BootstrapperFailure>),
+}
+
+impl BootstrapError {
+    pub fn modules<E>(error: E) -> Self
+    where
+        E: Into<BoxError>,
+    {
+        Self::Modules(error.into())
+    }
+
+    #[must_use]
+    pub fn with_failure(self, failure: BootstrapperFailure) -> Self {
+        let mut failures = match self {
+            Self::Modules(error) => vec![BootstrapperFailure {
+                name: "modules",
+                error: BootstrapperError::Failed(error),
+            }],
+            Self::Bootstrappers(failures) => failures,
+        };
+        failures.push(failure);
+
+        Self::Bootstrappers(failures)
+    }
+
+    pub fn report(&self) {
+        match self {
+            Self::Modules(error) => {
+                tracing::error!(
+                    error = %error,
+                    "bootstrap failed: modules could not be initialized"
+                );
+            },
+            Self::Bootstrappers(failures) => {
+                for failure in failures {
+                    tracing::error!(
+                        bootstrapper = failure.name,
+                        kind = failure.error.kind(),
+                        error = %failure.error,
+                        "bootstrap failed"
+                    );
+                }
+            },
+        }
+    }
+}
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+impl From<ShutdownError> for BootstrapError {
+    fn from(error: ShutdownError) -> Self {
+        Self::Bootstrappers(Vec::new()).with_shutdown_error(error)
+    }
+}
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+impl WithShutdownError for BootstrapError {
+    fn with_shutdown_error(self, error: ShutdownError) -> Self {
+        self.with_failure(BootstrapperFailure {
+            name: "opentelemetry",
+            error: BootstrapperError::Instrumentation(error.into()),
+        })
+    }
+}
+
+#[cfg(feature = "instrumentation-opentelemetry")]
+impl From<ExporterError> for BootstrapError {
+    fn from(error: ExporterError) -> Self {
+        Self::Bootstrappers(Vec::new()).with_failure(BootstrapperFailure {
+            name: "opentelemetry",
+            error: BootstrapperError::Instrumentation(error.into()),
+        })
+    }
+}
@@ -1,12 +1,23 @@
 #[cfg(feature = "instrumentation")]
 pub use instrumentation;
 #[doc(hidden)]
-pub use {entrait, mimalloc, mobc::Pool, pastey};
+pub use {application, entrait, mimalloc, mobc::Pool, pastey};
 
-pub use self::{bootstrap::Bootstrapper, config::ConfigExt};
+pub use self::{
+    bootstrap::Bootstrapper,
+    config::ConfigExt,
+    error::{
+        BootstrapError, BootstrapperError, BootstrapperFailure, BoxError,
+    },
+    shutdown::{Shutdown, shutdown_signal},
+};
 
 mod allocator;
 mod bootstrap;
-mod config;
+pub mod config;
+mod error;
 pub mod metadata;
 mod modules;
+pub mod scheduler;
+pub mod shutdown;
+pub mod startup;
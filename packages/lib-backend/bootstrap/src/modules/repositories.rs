@@ -18,6 +18,31 @@ macro_rules! impl_repositories {
         }
     };
 
+    (
+        @parse
+        struct: $struct: ident,
+        $delegate: ident: $implementation: ty => |$s: ident| $policy: expr
+    ) => {
+        impl $delegate<Self> for $struct {
+            type Target = $implementation;
+        }
+
+        impl $crate::application::di::Has<
+            $crate::application::cache::CachePolicy<$implementation>,
+        > for $struct {
+            fn get_dependency(
+                &self,
+            ) -> &$crate::application::cache::CachePolicy<$implementation> {
+                static POLICY: ::std::sync::OnceLock<
+                    $crate::application::cache::CachePolicy<$implementation>,
+                > = ::std::sync::OnceLock::new();
+
+                let $s = self;
+                POLICY.get_or_init(|| $policy)
+            }
+        }
+    };
+
     (
         @parse
         struct: $struct: ident,
@@ -39,6 +64,21 @@ macro_rules! impl_repositories {
         impl_repositories! { @parse struct: $struct, $($rest)* }
     };
 
+    (
+        @parse
+        struct: $struct: ident,
+        $delegate: ident: $implementation: ty => |$s: ident| $policy: expr,
+        $($rest: tt)*
+    ) => {
+        impl_repositories! {
+            @parse
+            struct: $struct,
+            $delegate: $implementation => |$s| $policy
+        }
+
+        impl_repositories! { @parse struct: $struct, $($rest)* }
+    };
+
     (
         @parse
         struct: $struct: ident,
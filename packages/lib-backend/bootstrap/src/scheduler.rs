@@ -0,0 +1,228 @@
+use std::{
+    marker::PhantomData,
+    str::FromStr as _,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use croner::Cron;
+pub use croner::errors::CronError;
+use entrait::Impl;
+use futures_util::future;
+use tracing::Instrument as _;
+
+use crate::{Bootstrapper, shutdown::Shutdown};
+
+#[derive(Clone, Debug)]
+enum Trigger {
+    Interval(Duration),
+    Cron(Box<Cron>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    trigger: Trigger,
+    jitter: Duration,
+}
+
+impl Schedule {
+    #[must_use]
+    pub const fn every(period: Duration) -> Self {
+        Self {
+            trigger: Trigger::Interval(period),
+            jitter: Duration::ZERO,
+        }
+    }
+
+    pub fn cron(expression: &str) -> Result<Self, CronError> {
+        let cron = Cron::from_str(expression)?;
+
+        Ok(Self {
+            trigger: Trigger::Cron(Box::new(cron)),
+            jitter: Duration::ZERO,
+        })
+    }
+
+    #[must_use]
+    pub const fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    fn next_delay(&self) -> Result<Duration, CronError> {
+        let delay = match &self.trigger {
+            Trigger::Interval(period) => *period,
+            Trigger::Cron(cron) => {
+                let now = Utc::now();
+                cron.find_next_occurrence(&now, false)?
+                    .signed_duration_since(now)
+                    .to_std()
+                    .unwrap_or_default()
+            },
+        };
+
+        let max_jitter = u64::try_from(self.jitter.as_millis())
+            .unwrap_or(u64::MAX);
+        let jitter = Duration::from_millis(fastrand::u64(0..=max_jitter));
+
+        Ok(delay.saturating_add(jitter))
+    }
+}
+
+pub type JobResult = Result<(), String>;
+
+#[async_trait]
+pub trait Job<M>: Send + Sync
+where
+    M: Send + Sync,
+{
+    async fn run(&self, deps: &Impl<M>) -> JobResult;
+}
+
+struct ScheduledJob<M> {
+    name: &'static str,
+    schedule: Schedule,
+    job: Box<dyn Job<M>>,
+}
+
+impl<M> ScheduledJob<M>
+where
+    M: Send + Sync,
+{
+    async fn run(&self, deps: &Impl<M>, shutdown: &Shutdown) {
+        loop {
+            let delay = match self.schedule.next_delay() {
+                Ok(delay) => delay,
+                Err(error) => {
+                    tracing::error!(
+                        job = self.name,
+                        error = %error,
+                        "failed to compute next job run, unscheduling"
+                    );
+                    return;
+                },
+            };
+
+            tokio::select! {
+                () = tokio::time::sleep(delay) => {},
+                () = shutdown.triggered() => return,
+            }
+
+            self.run_once(deps).await;
+        }
+    }
+
+    async fn run_once(&self, deps: &Impl<M>) {
+        let span = tracing::info_span!("job.run", job = self.name);
+        let started = Instant::now();
+
+        let result = self.job.run(deps).instrument(span.clone()).await;
+
+        metrics::histogram!("job_duration_seconds", "job" => self.name)
+            .record(started.elapsed().as_secs_f64());
+
+        let outcome = match result {
+            Ok(()) => "success",
+            Err(error) => {
+                span.in_scope(|| {
+                    tracing::error!(error = %error, "job failed");
+                });
+                "failure"
+            },
+        };
+
+        metrics::counter!(
+            "job_runs_total",
+            "job" => self.name,
+            "outcome" => outcome
+        )
+        .increment(1);
+    }
+}
+
+pub struct JobScheduler<M> {
+    jobs: Vec<ScheduledJob<M>>,
+}
+
+impl<M> JobScheduler<M>
+where
+    M: Send + Sync,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            jobs: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn job<J>(
+        mut self,
+        name: &'static str,
+        schedule: Schedule,
+        job: J,
+    ) -> Self
+    where
+        J: Job<M> + 'static,
+    {
+        self.jobs.push(ScheduledJob {
+            name,
+            schedule,
+            job: Box::new(job),
+        });
+        self
+    }
+
+    pub async fn run(self, deps: &Impl<M>, shutdown: Shutdown) {
+        future::join_all(self.jobs.iter().map(|job| job.run(deps, &shutdown)))
+            .await;
+
+        shutdown.triggered().await;
+
+        tracing::info!("job scheduler stopped");
+    }
+}
+
+impl<M> Default for JobScheduler<M>
+where
+    M: Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait Jobs {
+    type Config: fromenv::__private::FromEnv + Sync;
+    type Modules: Send + Sync;
+
+    fn register(
+        config: &Self::Config,
+        scheduler: JobScheduler<Self::Modules>,
+    ) -> Result<JobScheduler<Self::Modules>, CronError>;
+}
+
+pub struct Scheduler<J>(PhantomData<J>);
+
+#[async_trait]
+impl<J> Bootstrapper for Scheduler<J>
+where
+    J: Jobs + 'static,
+{
+    type Config = J::Config;
+    type Modules = J::Modules;
+    type Error = CronError;
+
+    async fn bootstrap(
+        config: &Self::Config,
+        deps: &Impl<Self::Modules>,
+        shutdown: Shutdown,
+    ) -> Result<(), Self::Error> {
+        J::register(config, JobScheduler::new())?
+            .run(deps, shutdown)
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    any::Any,
+    future::pending,
+    mem,
+    panic::AssertUnwindSafe,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+pub use application::shutdown::{Shutdown, ShutdownHook, ShutdownHooks};
+use fromenv::FromEnv;
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+use tokio::signal;
+
+use crate::error::{
+    BootstrapError, BootstrapperError, BootstrapperFailure, BoxError,
+};
+
+pub const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(30);
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(FromEnv)]
+#[env(prefix = "SHUTDOWN_")]
+pub struct ShutdownConfig {
+    #[env(default = "30")]
+    pub grace_period_secs: u64,
+    #[env(default = "10")]
+    pub hook_timeout_secs: u64,
+}
+
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = signal::ctrl_c().await {
+            tracing::error!(%error, "failed to install Ctrl+C handler");
+            pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            },
+            Err(error) => {
+                tracing::error!(%error, "failed to install signal handler");
+                pending::<()>().await;
+            },
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {},
+        () = terminate => {},
+    }
+}
+
+type Supervised<'a> = BoxFuture<'a, Result<(), BoxError>>;
+
+pub struct Coordinator<'a> {
+    shutdown: Shutdown,
+    grace_period: Duration,
+    hook_timeout: Duration,
+    bootstrappers: Vec<(&'static str, Supervised<'a>)>,
+}
+
+impl<'a> Coordinator<'a> {
+    #[must_use]
+    pub fn new(shutdown: Shutdown) -> Self {
+        Self {
+            shutdown,
+            grace_period: DEFAULT_GRACE_PERIOD,
+            hook_timeout: DEFAULT_HOOK_TIMEOUT,
+            bootstrappers: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn config(mut self, config: &ShutdownConfig) -> Self {
+        self.grace_period = Duration::from_secs(config.grace_period_secs);
+        self.hook_timeout = Duration::from_secs(config.hook_timeout_secs);
+        self
+    }
+
+    #[must_use]
+    pub fn bootstrapper<F, E>(
+        mut self,
+        name: &'static str,
+        bootstrapper: F,
+    ) -> Self
+    where
+        F: Future<Output = Result<(), E>> + Send + 'a,
+        E: Into<BoxError>,
+    {
+        self.bootstrappers.push((
+            name,
+            bootstrapper.map(|result| result.map_err(Into::into)).boxed(),
+        ));
+        self
+    }
+
+    pub async fn run(
+        self,
+        hooks: &ShutdownHooks,
+    ) -> Result<(), BootstrapError> {
+        let shutdown = &self.shutdown;
+        let failures = &Mutex::new(Vec::new());
+
+        let supervised = future::join_all(
+            self.bootstrappers
+                .into_iter()
+                .map(|(name, bootstrapper)| async move {
+                    if let Some(failure) =
+                        supervise(name, bootstrapper, shutdown).await
+                    {
+                        failures
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .push(failure);
+                    }
+                }),
+        );
+
+        let signal = async {
+            tokio::select! {
+                () = shutdown_signal() => {
+                    tracing::info!("shutdown signal received");
+                    shutdown.trigger();
+                },
+                () = shutdown.triggered() => {},
+            }
+        };
+
+        let grace_period = async {
+            shutdown.triggered().await;
+            tokio::time::sleep(self.grace_period).await;
+        };
+
+        tokio::select! {
+            _ = future::join(supervised, signal) => {
+                tracing::info!("all bootstrappers stopped");
+            },
+            () = grace_period => {
+                tracing::warn!(
+                    grace_period_ms = self.grace_period.as_millis(),
+                    "grace period elapsed, abandoning remaining bootstrappers"
+                );
+            },
+        }
+
+        hooks.run(self.hook_timeout).await;
+
+        let failures = mem::take(
+            &mut *failures.lock().unwrap_or_else(PoisonError::into_inner),
+        );
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(BootstrapError::Bootstrappers(failures))
+        }
+    }
+}
+
+async fn supervise(
+    name: &'static str,
+    bootstrapper: Supervised<'_>,
+    shutdown: &Shutdown,
+) -> Option<BootstrapperFailure> {
+    let error = match AssertUnwindSafe(bootstrapper).catch_unwind().await {
+        Ok(Ok(())) if shutdown.is_triggered() => {
+            tracing::info!(bootstrapper = name, "bootstrapper stopped");
+            None
+        },
+        Ok(Ok(())) => Some(BootstrapperError::ExitedEarly),
+        Ok(Err(error)) => Some(BootstrapperError::Failed(error)),
+        Err(payload) => Some(BootstrapperError::Panicked(
+            panic_message(payload.as_ref()).to_owned(),
+        )),
+    };
+
+    if let Some(error) = &error {
+        tracing::error!(
+            bootstrapper = name,
+            error = %error,
+            "bootstrapper failed, shutting down"
+        );
+    }
+
+    shutdown.trigger();
+
+    error.map(|error| BootstrapperFailure {
+        name,
+        error,
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
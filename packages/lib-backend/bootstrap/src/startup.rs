@@ -0,0 +1,195 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use application::{
+    health::{CheckResult, DEFAULT_TIMEOUT, HealthCheck},
+    retry::RetryPolicy,
+};
+use fromenv::FromEnv;
+use futures_util::{
+    FutureExt as _,
+    future::{BoxFuture, join_all},
+};
+use tokio::time::timeout;
+
+pub const DEFAULT_DEADLINE: Duration = Duration::from_secs(60);
+
+#[derive(FromEnv)]
+#[env(prefix = "STARTUP_")]
+pub struct StartupConfig {
+    #[env(default = "60")]
+    pub deadline_secs: u64,
+    #[env(default = "2000")]
+    pub probe_timeout_ms: u64,
+}
+
+#[derive(Debug)]
+pub struct DependencyFailure {
+    pub name: &'static str,
+    pub error: String,
+}
+
+impl Display for DependencyFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.error)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "dependencies are unavailable after {elapsed_ms}ms: {}",
+    .failures
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+)]
+pub struct DependenciesUnavailable {
+    pub elapsed_ms: u64,
+    pub failures: Vec<DependencyFailure>,
+}
+
+type Probe<'a> =
+    Box<dyn Fn() -> BoxFuture<'static, CheckResult> + Send + Sync + 'a>;
+
+pub struct WaitForDependencies<'a> {
+    deadline: Duration,
+    probe_timeout: Duration,
+    backoff: RetryPolicy,
+    probes: Vec<(&'static str, Probe<'a>)>,
+}
+
+impl<'a> WaitForDependencies<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            deadline: DEFAULT_DEADLINE,
+            probe_timeout: DEFAULT_TIMEOUT,
+            backoff: RetryPolicy::new()
+                .initial_delay(Duration::from_millis(250))
+                .max_delay(Duration::from_secs(5)),
+            probes: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    #[must_use]
+    pub const fn probe_timeout(mut self, probe_timeout: Duration) -> Self {
+        self.probe_timeout = probe_timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn backoff(mut self, backoff: RetryPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    #[must_use]
+    pub fn probe<C>(mut self, name: &'static str, check: &'a C) -> Self
+    where
+        C: HealthCheck + Sync,
+    {
+        self.probes
+            .push((name, Box::new(move || check.health_check().boxed())));
+        self
+    }
+
+    pub async fn wait(self) -> Result<(), DependenciesUnavailable> {
+        let started = Instant::now();
+        let probe_timeout = self.probe_timeout;
+        let mut pending = self.probes;
+        let mut attempt: u32 = 1;
+
+        loop {
+            let outcomes = join_all(pending.iter().map(|(_, probe)| async {
+                timeout(probe_timeout, probe()).await.unwrap_or_else(|_| {
+                    Err(format!(
+                        "timed out after {}ms",
+                        probe_timeout.as_millis()
+                    ))
+                })
+            }))
+            .await;
+
+            let mut failures = Vec::new();
+            let mut still_pending = Vec::new();
+
+            for ((name, probe), outcome) in pending.into_iter().zip(outcomes) {
+                match outcome {
+                    Ok(()) => {
+                        tracing::info!(
+                            dependency = name,
+                            attempt,
+                            "dependency is ready"
+                        );
+                    },
+                    Err(error) => {
+                        failures.push(DependencyFailure {
+                            name,
+                            error,
+                        });
+                        still_pending.push((name, probe));
+                    },
+                }
+            }
+
+            let elapsed = started.elapsed();
+            let elapsed_ms =
+                u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+
+            if failures.is_empty() {
+                tracing::info!(elapsed_ms, "all dependencies are ready");
+                return Ok(());
+            }
+
+            let remaining = self.deadline.saturating_sub(elapsed);
+            if remaining.is_zero() {
+                let error = DependenciesUnavailable {
+                    elapsed_ms,
+                    failures,
+                };
+                tracing::error!(error = %error, "giving up on dependencies");
+                return Err(error);
+            }
+
+            let delay = self.backoff.backoff(attempt).min(remaining);
+
+            for failure in &failures {
+                tracing::warn!(
+                    dependency = failure.name,
+                    attempt,
+                    delay_ms =
+                        u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                    error = %failure.error,
+                    "waiting for dependency"
+                );
+            }
+
+            tokio::time::sleep(delay).await;
+            pending = still_pending;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+}
+
+impl Default for WaitForDependencies<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&StartupConfig> for WaitForDependencies<'_> {
+    fn from(config: &StartupConfig) -> Self {
+        Self::new()
+            .deadline(Duration::from_secs(config.deadline_secs))
+            .probe_timeout(Duration::from_millis(config.probe_timeout_ms))
+    }
+}
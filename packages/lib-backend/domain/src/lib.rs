@@ -6,6 +6,7 @@ pub use pastey;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+pub mod pagination;
 pub mod validation;
 
 #[derive_where(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -0,0 +1,235 @@
+use std::{fmt, sync::LazyLock};
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use uuid::Uuid;
+
+use crate::{
+    Id, impl_try_from_external_input,
+    validation::{
+        Constraints, constraints,
+        error::{ValidationErrors, ValidationResult},
+    },
+};
+
+pub const DEFAULT_PAGE_LIMIT: u16 = 20;
+pub const MAX_PAGE_LIMIT: u16 = 100;
+
+const FORWARD_TAG: u8 = b'f';
+const BACKWARD_TAG: u8 = b'b';
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Cursor {
+    pub direction: Direction,
+    pub position: Uuid,
+}
+
+impl Cursor {
+    #[must_use]
+    pub const fn after<T>(id: Id<T>) -> Self {
+        Self {
+            direction: Direction::Forward,
+            position: id.value,
+        }
+    }
+
+    #[must_use]
+    pub const fn before<T>(id: Id<T>) -> Self {
+        Self {
+            direction: Direction::Backward,
+            position: id.value,
+        }
+    }
+
+    #[must_use]
+    pub const fn position<T>(self) -> Id<T> {
+        Id::new(self.position)
+    }
+
+    #[must_use]
+    pub fn encode(self) -> String {
+        let tag = match self.direction {
+            Direction::Forward => FORWARD_TAG,
+            Direction::Backward => BACKWARD_TAG,
+        };
+
+        let mut bytes = Vec::with_capacity(17);
+        bytes.push(tag);
+        bytes.extend_from_slice(self.position.as_bytes());
+
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    #[must_use]
+    pub fn decode(encoded: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+        let (tag, position) = bytes.split_first()?;
+
+        let direction = match *tag {
+            FORWARD_TAG => Direction::Forward,
+            BACKWARD_TAG => Direction::Backward,
+            _ => return None,
+        };
+
+        Uuid::from_slice(position).ok().map(|position| Self {
+            direction,
+            position,
+        })
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl TryFrom<String> for Cursor {
+    type Error = ValidationErrors;
+
+    fn try_from(value: String) -> ValidationResult<Self> {
+        Self::decode(&value).ok_or_else(|| {
+            ValidationErrors::with_error("must be a valid cursor", value)
+        })
+    }
+}
+
+impl_try_from_external_input!(domain_type = Cursor, input_type = String,);
+
+static LIMIT_CONSTRAINTS: LazyLock<Constraints<i64>> = LazyLock::new(|| {
+    Constraints::builder()
+        .add_constraint(
+            constraints::range::Min::with_err(|_, limit| {
+                format!("can't be less than {limit}")
+            })
+            .limit(1)
+            .build(),
+        )
+        .add_constraint(
+            constraints::range::Max::with_err(|_, limit| {
+                format!("can't be greater than {limit}")
+            })
+            .limit(i64::from(MAX_PAGE_LIMIT))
+            .build(),
+        )
+        .build()
+});
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PageLimit(u16);
+
+impl PageLimit {
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn fetch_size(self) -> i64 {
+        i64::from(self.0).saturating_add(1)
+    }
+}
+
+impl Default for PageLimit {
+    fn default() -> Self {
+        Self(DEFAULT_PAGE_LIMIT)
+    }
+}
+
+impl TryFrom<i64> for PageLimit {
+    type Error = ValidationErrors;
+
+    fn try_from(value: i64) -> ValidationResult<Self> {
+        LIMIT_CONSTRAINTS.check(&value).into_result(|_| ())?;
+
+        u16::try_from(value).map(Self).map_err(|_| {
+            ValidationErrors::with_error("must be a valid page limit", value)
+        })
+    }
+}
+
+impl From<PageLimit> for i64 {
+    fn from(limit: PageLimit) -> Self {
+        limit.0.into()
+    }
+}
+
+impl_try_from_external_input!(domain_type = PageLimit, input_type = i64,);
+
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct PageRequest {
+    pub limit: PageLimit,
+    pub cursor: Option<Cursor>,
+}
+
+impl PageRequest {
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        self.cursor
+            .map_or(Direction::Forward, |cursor| cursor.direction)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    pub fn from_keyset<E, F>(
+        request: &PageRequest,
+        mut rows: Vec<T>,
+        key: F,
+    ) -> Self
+    where
+        F: Fn(&T) -> Id<E>,
+    {
+        let limit = usize::from(request.limit.get());
+        let has_more = rows.len() > limit;
+        let has_cursor = request.cursor.is_some();
+        rows.truncate(limit);
+
+        let direction = request.direction();
+        if direction == Direction::Backward {
+            rows.reverse();
+        }
+
+        let (has_prev, has_next) = match direction {
+            Direction::Forward => (has_cursor, has_more),
+            Direction::Backward => (has_more, has_cursor),
+        };
+
+        let next = rows
+            .last()
+            .filter(|_| has_next)
+            .map(|item| Cursor::after(key(item)));
+        let prev = rows
+            .first()
+            .filter(|_| has_prev)
+            .map(|item| Cursor::before(key(item)));
+
+        Self {
+            items: rows,
+            next,
+            prev,
+        }
+    }
+
+    pub fn map<U, F>(self, f: F) -> Page<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next: self.next,
+            prev: self.prev,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS outbox
+(
+    id           uuid        NOT NULL PRIMARY KEY,
+    aggregate_id text        NOT NULL,
+    event_type   text        NOT NULL,
+    payload      jsonb       NOT NULL,
+    attempts     integer     NOT NULL DEFAULT 0,
+    last_error   text,
+    created_at   timestamptz NOT NULL DEFAULT now(),
+    available_at timestamptz NOT NULL DEFAULT now(),
+    delivered_at timestamptz
+);
+
+CREATE INDEX IF NOT EXISTS outbox_pending_idx
+    ON outbox (available_at, created_at)
+    WHERE delivered_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS outbox_aggregate_pending_idx
+    ON outbox (aggregate_id, created_at)
+    WHERE delivered_at IS NULL;
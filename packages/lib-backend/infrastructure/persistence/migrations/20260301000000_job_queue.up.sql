@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS job_queue
+(
+    id           uuid        NOT NULL PRIMARY KEY,
+    queue        text        NOT NULL,
+    job_type     text        NOT NULL,
+    payload      jsonb       NOT NULL,
+    status       text        NOT NULL DEFAULT 'pending',
+    attempts     integer     NOT NULL DEFAULT 0,
+    max_attempts integer     NOT NULL,
+    last_error   text,
+    created_at   timestamptz NOT NULL DEFAULT now(),
+    available_at timestamptz NOT NULL DEFAULT now(),
+    locked_until timestamptz,
+    lease_token  uuid,
+    completed_at timestamptz
+);
+
+CREATE INDEX IF NOT EXISTS job_queue_claimable_idx
+    ON job_queue (queue, available_at)
+    WHERE status IN ('pending', 'running');
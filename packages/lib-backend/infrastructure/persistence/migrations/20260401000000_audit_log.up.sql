@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS audit_log
+(
+    id          uuid        NOT NULL PRIMARY KEY,
+    occurred_at timestamptz NOT NULL,
+    actor_kind  text        NOT NULL,
+    actor_id    uuid,
+    action      text        NOT NULL,
+    target_kind text,
+    target_id   text,
+    outcome     text        NOT NULL,
+    request_id  uuid,
+    ip          inet,
+    details     jsonb       NOT NULL DEFAULT 'null'
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_actor_idx
+    ON audit_log (actor_id, id)
+    WHERE actor_id IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS audit_log_action_idx
+    ON audit_log (action, id);
+
+CREATE OR REPLACE FUNCTION audit_log_append_only() RETURNS trigger AS
+$$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER audit_log_append_only
+    BEFORE UPDATE OR DELETE OR TRUNCATE
+    ON audit_log
+    FOR EACH STATEMENT
+EXECUTE FUNCTION audit_log_append_only();
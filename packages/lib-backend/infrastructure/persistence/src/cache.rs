@@ -0,0 +1,323 @@
+use std::time::Duration;
+
+use application::{cache::CachePolicy, di::Has, transaction::after_commit};
+use redis_driver::cmd;
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::time::{Instant, sleep};
+use uuid::Uuid;
+
+use crate::{RedisPool, redis::Namespace};
+
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+const UNLOCK_SCRIPT: &str = "\
+    if redis.call('GET', KEYS[1]) == ARGV[1] then \
+        return redis.call('DEL', KEYS[1]) \
+    end \
+    return 0";
+
+enum Lookup<T> {
+    Found(Option<T>),
+    Missing,
+}
+
+fn millis(ttl: Duration) -> u64 {
+    u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX).max(1)
+}
+
+fn record(cache: &'static str, result: &'static str) {
+    metrics::counter!(
+        "cache_requests_total",
+        "cache" => cache,
+        "result" => result
+    )
+    .increment(1);
+}
+
+struct Cache<'a> {
+    pool: &'a RedisPool,
+    namespace: Namespace,
+}
+
+impl<'a> Cache<'a> {
+    fn new(pool: &'a RedisPool, namespace: &Namespace, name: &str) -> Self {
+        Self {
+            pool,
+            namespace: namespace.nest("cache").nest(name),
+        }
+    }
+
+    async fn get<T>(&self, key: &str) -> Result<Lookup<T>, String>
+    where
+        T: DeserializeOwned,
+    {
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let value = cmd("GET")
+            .arg(self.namespace.key(key))
+            .query_async::<Option<Vec<u8>>>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())?;
+
+        value.map_or(Ok(Lookup::Missing), |value| {
+            serde_json::from_slice::<Option<T>>(&value)
+                .map(Lookup::Found)
+                .map_err(|error| error.to_string())
+        })
+    }
+
+    async fn set<T>(
+        &self,
+        key: &str,
+        value: &Option<T>,
+        ttl: Duration,
+    ) -> Result<(), String>
+    where
+        T: Serialize,
+    {
+        let value =
+            serde_json::to_vec(value).map_err(|error| error.to_string())?;
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("SET")
+            .arg(self.namespace.key(key))
+            .arg(value)
+            .arg("PX")
+            .arg(millis(ttl))
+            .query_async::<()>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    async fn lock(
+        &self,
+        key: &str,
+        ttl: Duration,
+    ) -> Result<Option<String>, String> {
+        let token = Uuid::new_v4().to_string();
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("SET")
+            .arg(self.namespace.nest("lock").key(key))
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(millis(ttl))
+            .query_async::<Option<String>>(&mut *connection)
+            .await
+            .map(|locked| locked.map(|_| token))
+            .map_err(|error| error.to_string())
+    }
+
+    async fn unlock(&self, key: &str, token: &str) -> Result<(), String> {
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("EVAL")
+            .arg(UNLOCK_SCRIPT)
+            .arg(1)
+            .arg(self.namespace.nest("lock").key(key))
+            .arg(token)
+            .query_async::<i64>(&mut *connection)
+            .await
+            .map(|released| {
+                if released == 0 {
+                    tracing::debug!(key, "cache lock expired before release");
+                }
+            })
+            .map_err(|error| error.to_string())
+    }
+
+    async fn delete(&self, keys: Vec<String>) -> Result<(), String> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("DEL")
+            .arg(keys)
+            .query_async::<()>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    async fn wait<T>(&self, key: &str, timeout: Duration) -> Option<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let deadline = Instant::now().checked_add(timeout)?;
+
+        while Instant::now() < deadline {
+            sleep(LOCK_POLL_INTERVAL).await;
+
+            match self.get::<T>(key).await {
+                Ok(Lookup::Found(value)) => return Some(value),
+                Ok(Lookup::Missing) => {},
+                Err(_) => return None,
+            }
+        }
+
+        None
+    }
+}
+
+pub trait HasReadThroughCacheExt {
+    fn read_through<R, T, E, F, Fut>(
+        &self,
+        policy: &CachePolicy<R>,
+        key: &str,
+        load: F,
+    ) -> impl Future<Output = Result<Option<T>, E>> + Send
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        E: Send,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<Option<T>, E>> + Send;
+
+    fn invalidate<R>(
+        &self,
+        policy: &CachePolicy<R>,
+        keys: &[&str],
+    ) -> impl Future<Output = ()> + Send;
+}
+
+impl<D> HasReadThroughCacheExt for D
+where
+    D: Has<RedisPool> + Has<Namespace> + Sync,
+{
+    async fn read_through<R, T, E, F, Fut>(
+        &self,
+        policy: &CachePolicy<R>,
+        key: &str,
+        load: F,
+    ) -> Result<Option<T>, E>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        E: Send,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<Option<T>, E>> + Send,
+    {
+        let policy = *policy;
+        let pool: &RedisPool = self.get_dependency();
+        let namespace: &Namespace = self.get_dependency();
+        let cache = Cache::new(pool, namespace, policy.name);
+
+        match cache.get::<T>(key).await {
+            Ok(Lookup::Found(value)) => {
+                let result = if value.is_some() { "hit" } else { "negative" };
+                record(policy.name, result);
+                return Ok(value);
+            },
+            Ok(Lookup::Missing) => record(policy.name, "miss"),
+            Err(error) => {
+                tracing::warn!(
+                    cache = policy.name,
+                    error = %error,
+                    "cache is unavailable, bypassing"
+                );
+                record(policy.name, "error");
+                return load().await;
+            },
+        }
+
+        let token = match cache.lock(key, policy.lock_ttl).await {
+            Ok(Some(token)) => Some(token),
+            Ok(None) => {
+                if let Some(value) = cache.wait(key, policy.lock_wait).await {
+                    record(policy.name, "coalesced");
+                    return Ok(value);
+                }
+                None
+            },
+            Err(error) => {
+                tracing::warn!(
+                    cache = policy.name,
+                    error = %error,
+                    "failed to acquire cache lock"
+                );
+                None
+            },
+        };
+
+        let value = load().await;
+
+        if let Ok(value) = &value {
+            let ttl = match value {
+                Some(_) => Some(policy.ttl),
+                None => policy.negative_ttl,
+            };
+
+            if let Some(ttl) = ttl
+                && let Err(error) = cache.set(key, value, ttl).await
+            {
+                tracing::warn!(
+                    cache = policy.name,
+                    error = %error,
+                    "failed to store cache entry"
+                );
+            }
+        }
+
+        if let Some(token) = token
+            && let Err(error) = cache.unlock(key, &token).await
+        {
+            tracing::warn!(
+                cache = policy.name,
+                error = %error,
+                "failed to release cache lock"
+            );
+        }
+
+        value
+    }
+
+    async fn invalidate<R>(&self, policy: &CachePolicy<R>, keys: &[&str]) {
+        let pool: &RedisPool = self.get_dependency();
+        let namespace: &Namespace = self.get_dependency();
+        let cache = Cache::new(pool, namespace, policy.name);
+
+        let keys = keys
+            .iter()
+            .map(|key| cache.namespace.key(key))
+            .collect::<Vec<_>>();
+        let pool = pool.clone();
+        let namespace = cache.namespace;
+        let name = policy.name;
+
+        after_commit(async move {
+            let cache = Cache {
+                pool: &pool,
+                namespace,
+            };
+
+            if let Err(error) = cache.delete(keys).await {
+                tracing::error!(
+                    cache = name,
+                    error = %error,
+                    "failed to invalidate cache entries"
+                );
+            }
+        })
+        .await;
+    }
+}
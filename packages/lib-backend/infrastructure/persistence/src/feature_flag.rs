@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use application::feature_flag::{FlagDefinition, FlagProvider, Flags};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use redis_driver::cmd;
+
+use crate::{RedisPool, redis::Namespace};
+
+pub struct RedisFlagProvider {
+    pool: RedisPool,
+    key: String,
+}
+
+impl RedisFlagProvider {
+    #[must_use]
+    pub fn new(pool: RedisPool, namespace: &Namespace) -> Self {
+        Self {
+            pool,
+            key: namespace.key("feature_flags"),
+        }
+    }
+
+    async fn load_inner(&self) -> Result<Flags, String> {
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let entries = cmd("HGETALL")
+            .arg(&self.key)
+            .query_async::<HashMap<String, String>>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())?;
+
+        entries
+            .into_iter()
+            .map(|(name, definition)| {
+                serde_json::from_str::<FlagDefinition>(&definition)
+                    .map(|definition| (name.clone(), definition))
+                    .map_err(|error| {
+                        format!("invalid definition for flag `{name}`: {error}")
+                    })
+            })
+            .collect()
+    }
+}
+
+impl FlagProvider for RedisFlagProvider {
+    fn load(&self) -> BoxFuture<'_, Result<Flags, String>> {
+        self.load_inner().boxed()
+    }
+}
@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use application::idempotency::{
+    IdempotencyRecord, IdempotencyStore, StoreResult, StoredResponse,
+};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use redis_driver::cmd;
+use serde::{Deserialize, Serialize};
+
+use crate::{RedisPool, redis::Namespace};
+
+const LOCK_ATTEMPTS: usize = 2;
+
+const RELEASE_SCRIPT: &str = "\
+    if redis.call('GET', KEYS[1]) == ARGV[1] then \
+        return redis.call('DEL', KEYS[1]) \
+    end \
+    return 0";
+
+#[derive(Serialize, Deserialize)]
+struct RecordHead {
+    fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response: Option<ResponseHead>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ResponseHead {
+    status: u16,
+    headers: Vec<(String, String)>,
+}
+
+fn encode(
+    fingerprint: &str,
+    owner: Option<&str>,
+    response: Option<StoredResponse>,
+) -> StoreResult<Vec<u8>> {
+    let (head, body) = match response {
+        Some(response) => (
+            Some(ResponseHead {
+                status: response.status,
+                headers: response.headers,
+            }),
+            response.body,
+        ),
+        None => (None, Vec::new()),
+    };
+
+    let mut value = serde_json::to_vec(&RecordHead {
+        fingerprint: fingerprint.to_owned(),
+        owner: owner.map(str::to_owned),
+        response: head,
+    })
+    .map_err(|error| error.to_string())?;
+
+    value.push(b'\n');
+    value.extend(body);
+
+    Ok(value)
+}
+
+fn decode(value: &[u8]) -> StoreResult<IdempotencyRecord> {
+    let separator = value
+        .iter()
+        .position(|byte| *byte == b'\n')
+        .ok_or("malformed idempotency record")?;
+    let (head, rest) = value.split_at(separator);
+    let body = rest.get(1..).unwrap_or_default();
+
+    let head = serde_json::from_slice::<RecordHead>(head)
+        .map_err(|error| error.to_string())?;
+
+    Ok(match head.response {
+        Some(response) => IdempotencyRecord::Completed {
+            fingerprint: head.fingerprint,
+            response: StoredResponse {
+                status: response.status,
+                headers: response.headers,
+                body: body.to_vec(),
+            },
+        },
+        None => IdempotencyRecord::InFlight {
+            fingerprint: head.fingerprint,
+        },
+    })
+}
+
+fn millis(ttl: Duration) -> u64 {
+    u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX).max(1)
+}
+
+pub struct RedisIdempotencyStore {
+    pool: RedisPool,
+    namespace: Namespace,
+}
+
+impl RedisIdempotencyStore {
+    #[must_use]
+    pub fn new(pool: RedisPool, namespace: &Namespace) -> Self {
+        Self {
+            pool,
+            namespace: namespace.nest("idempotency"),
+        }
+    }
+
+    async fn try_lock_inner(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        owner: &str,
+        ttl: Duration,
+    ) -> StoreResult<Option<IdempotencyRecord>> {
+        let key = self.namespace.key(key);
+        let value = encode(fingerprint, Some(owner), None)?;
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        for _ in 0..LOCK_ATTEMPTS {
+            let locked = cmd("SET")
+                .arg(&key)
+                .arg(&value)
+                .arg("NX")
+                .arg("PX")
+                .arg(millis(ttl))
+                .query_async::<Option<String>>(&mut *connection)
+                .await
+                .map_err(|error| error.to_string())?;
+
+            if locked.is_some() {
+                return Ok(None);
+            }
+
+            let existing = cmd("GET")
+                .arg(&key)
+                .query_async::<Option<Vec<u8>>>(&mut *connection)
+                .await
+                .map_err(|error| error.to_string())?;
+
+            if let Some(existing) = existing {
+                return decode(&existing).map(Some);
+            }
+        }
+
+        Err(format!("could not lock idempotency key `{key}`"))
+    }
+
+    async fn complete_inner(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        response: StoredResponse,
+        ttl: Duration,
+    ) -> StoreResult<()> {
+        let value = encode(fingerprint, None, Some(response))?;
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("SET")
+            .arg(self.namespace.key(key))
+            .arg(value)
+            .arg("PX")
+            .arg(millis(ttl))
+            .query_async::<()>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    async fn release_inner(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        owner: &str,
+    ) -> StoreResult<()> {
+        let value = encode(fingerprint, Some(owner), None)?;
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        cmd("EVAL")
+            .arg(RELEASE_SCRIPT)
+            .arg(1)
+            .arg(self.namespace.key(key))
+            .arg(value)
+            .query_async::<i64>(&mut *connection)
+            .await
+            .map(|released| {
+                if released == 0 {
+                    tracing::debug!(
+                        key,
+                        "idempotency lock expired or changed hands before \
+                         release"
+                    );
+                }
+            })
+            .map_err(|error| error.to_string())
+    }
+}
+
+impl IdempotencyStore for RedisIdempotencyStore {
+    fn try_lock<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        owner: &'a str,
+        ttl: Duration,
+    ) -> BoxFuture<'a, StoreResult<Option<IdempotencyRecord>>> {
+        self.try_lock_inner(key, fingerprint, owner, ttl).boxed()
+    }
+
+    fn complete<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        response: StoredResponse,
+        ttl: Duration,
+    ) -> BoxFuture<'a, StoreResult<()>> {
+        self.complete_inner(key, fingerprint, response, ttl).boxed()
+    }
+
+    fn release<'a>(
+        &'a self,
+        key: &'a str,
+        fingerprint: &'a str,
+        owner: &'a str,
+    ) -> BoxFuture<'a, StoreResult<()>> {
+        self.release_inner(key, fingerprint, owner).boxed()
+    }
+}
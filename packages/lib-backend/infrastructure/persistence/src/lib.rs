@@ -1,37 +1,129 @@
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
 
 use application::{
+    circuit_breaker::{CircuitBreaker, CircuitBreakerError},
     di::Has,
     health::{CheckResult, HealthCheck},
+    shutdown::ShutdownHook,
 };
+use futures_util::{FutureExt as _, future::BoxFuture};
 use mobc::{Connection, Manager, Pool as MobcPool};
 #[cfg(feature = "redis")]
 pub use redis;
 #[cfg(feature = "sqlx")]
 pub use sqlx;
-use tap::Pipe as _;
 
+#[cfg(feature = "redis")]
+pub mod cache;
 pub mod entity;
+#[cfg(feature = "redis")]
+pub mod feature_flag;
+#[cfg(feature = "redis")]
+pub mod idempotency;
+#[cfg(feature = "redis")]
+pub mod rate_limit;
 pub mod repository;
 
 #[cfg(feature = "mobc-sqlx")]
 pub mod mobc_sqlx;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "mobc-sqlx")]
+pub mod transaction;
 
 #[doc(hidden)]
 pub use {derive_where::derive_where, pastey};
 
-pub struct Pool<M: Manager>(MobcPool<M>);
+const CLOSE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(thiserror::Error, Debug)]
+pub enum PoolError<E> {
+    #[error(transparent)]
+    Pool(#[from] mobc::Error<E>),
+
+    #[error("circuit breaker `{0}` is open")]
+    CircuitOpen(&'static str),
+
+    #[error("pool is closed")]
+    Closed,
+}
+
+pub struct Pool<M: Manager> {
+    pool: MobcPool<M>,
+    circuit_breaker: Option<CircuitBreaker>,
+    closed: Arc<AtomicBool>,
+}
 
 impl<M: Manager> Pool<M> {
     #[must_use]
     pub fn new(manager: M) -> Self {
-        manager.pipe(MobcPool::new).pipe(Self)
+        Self {
+            pool: MobcPool::new(manager),
+            circuit_breaker: None,
+            closed: Arc::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    #[must_use]
+    pub const fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    pub async fn get_guarded(
+        &self,
+    ) -> Result<Connection<M>, PoolError<M::Error>> {
+        if self.is_closed() {
+            return Err(PoolError::Closed);
+        }
+
+        let Some(breaker) = &self.circuit_breaker else {
+            return self.pool.get().await.map_err(PoolError::Pool);
+        };
+
+        breaker
+            .call(self.pool.get())
+            .await
+            .map_err(|error| match error {
+                CircuitBreakerError::Open(name) => PoolError::CircuitOpen(name),
+                CircuitBreakerError::Operation(error) => PoolError::Pool(error),
+            })
+    }
+
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    pub async fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.pool.set_max_idle_conns(0).await;
+
+        while self.pool.state().await.connections > 0 {
+            tokio::time::sleep(CLOSE_POLL_INTERVAL).await;
+        }
     }
 }
 
 impl<M: Manager> Clone for Pool<M> {
     fn clone(&self) -> Self {
-        self.0.clone().pipe(Self)
+        Self {
+            pool: self.pool.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            closed: Arc::clone(&self.closed),
+        }
     }
 }
 
@@ -39,7 +131,7 @@ impl<M: Manager> Deref for Pool<M> {
     type Target = MobcPool<M>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.pool
     }
 }
 
@@ -51,9 +143,14 @@ where
     fn health_check(
         &self,
     ) -> impl Future<Output = CheckResult> + Send + 'static {
-        let pool = self.0.clone();
+        let pool = self.pool.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
 
         async move {
+            if let Some(breaker) = circuit_breaker {
+                breaker.health_check().await?;
+            }
+
             pool.get()
                 .await
                 .map(drop)
@@ -62,6 +159,20 @@ where
     }
 }
 
+impl<M> ShutdownHook for Pool<M>
+where
+    M: Manager,
+{
+    fn shutdown(&self) -> BoxFuture<'_, Result<(), String>> {
+        async move {
+            self.close().await;
+
+            Ok(())
+        }
+        .boxed()
+    }
+}
+
 #[cfg(feature = "sqlx")]
 pub type SqlxPool<DB> = Pool<sqlx::SqlxConnectionManager<DB>>;
 
@@ -74,7 +185,7 @@ where
 {
     fn get_connection(
         &self,
-    ) -> impl Future<Output = Result<Connection<M>, mobc::Error<M::Error>>>;
+    ) -> impl Future<Output = Result<Connection<M>, PoolError<M::Error>>>;
 }
 
 impl<D, M> HasPoolExt<M> for D
@@ -85,8 +196,8 @@ where
     fn get_connection(
         &self,
     ) -> impl Future<
-        Output = Result<Connection<M>, mobc::Error<<M as Manager>::Error>>,
+        Output = Result<Connection<M>, PoolError<<M as Manager>::Error>>,
     > {
-        self.get_dependency().get()
+        self.get_dependency().get_guarded()
     }
 }
@@ -1,8 +1,3 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
 use mobc_sqlx::{
     SqlxConnectionManager, SqlxMigrationExt as _,
     mobc::async_trait,
@@ -14,9 +9,16 @@ use mobc_sqlx::{
 
 use super::Pool;
 
+#[derive(thiserror::Error, Debug)]
+#[error("failed to run migrations: {0}")]
+pub struct MigrationError(String);
+
 #[async_trait]
 pub trait MigratorExt {
-    async fn migrate<DB>(&self, pool: &Pool<SqlxConnectionManager<DB>>)
+    async fn migrate<DB>(
+        &self,
+        pool: &Pool<SqlxConnectionManager<DB>>,
+    ) -> Result<(), MigrationError>
     where
         DB: Database + Sync,
         <DB as Database>::Connection: Migrate;
@@ -24,26 +26,32 @@ pub trait MigratorExt {
 
 #[async_trait]
 impl MigratorExt for Migrator {
-    async fn migrate<DB>(&self, pool: &Pool<SqlxConnectionManager<DB>>)
+    async fn migrate<DB>(
+        &self,
+        pool: &Pool<SqlxConnectionManager<DB>>,
+    ) -> Result<(), MigrationError>
     where
         DB: Database + Sync,
         <DB as Database>::Connection: Migrate,
     {
-        pool.0
+        pool.pool
             .migrate(&self)
             .await
-            .expect("failed to run migrations");
+            .map_err(|error| MigrationError(error.to_string()))
     }
 }
 
 pub async fn migrate_all<DB>(
     pool: &Pool<SqlxConnectionManager<DB>>,
     migrators: &[&Migrator],
-) where
+) -> Result<(), MigrationError>
+where
     DB: Database + Sync,
     <DB as Database>::Connection: Migrate,
 {
     for migrator in migrators {
-        migrator.migrate(pool).await;
+        migrator.migrate(pool).await?;
     }
+
+    Ok(())
 }
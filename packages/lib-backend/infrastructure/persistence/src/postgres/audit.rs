@@ -0,0 +1,210 @@
+use std::{net::IpAddr, sync::LazyLock};
+
+use application::audit::{
+    Actor, AuditFilter, AuditOutcome, AuditRecord, AuditStore, AuditTarget,
+};
+use domain::{
+    Id,
+    pagination::{Page, PageRequest},
+};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use mobc_sqlx::sqlx::{
+    self, Postgres,
+    migrate::Migrator,
+    types::{
+        JsonValue, Uuid,
+        chrono::{DateTime, Utc},
+    },
+};
+
+use crate::{PoolError, SqlxPool, postgres::pagination::KeysetQuery};
+
+#[expect(
+    clippy::unreadable_literal,
+    reason = "migration versions are timestamps"
+)]
+const AUDIT_LOG_MIGRATION_VERSION: i64 = 20260401000000;
+
+pub static AUDIT_LOG_MIGRATOR: LazyLock<Migrator> = LazyLock::new(|| {
+    super::migrator(
+        AUDIT_LOG_MIGRATION_VERSION,
+        "audit_log",
+        include_str!("../../migrations/20260401000000_audit_log.up.sql"),
+        include_str!("../../migrations/20260401000000_audit_log.down.sql"),
+    )
+});
+
+type AuditRow = (
+    Uuid,
+    DateTime<Utc>,
+    String,
+    Option<Uuid>,
+    String,
+    Option<String>,
+    Option<String>,
+    String,
+    Option<Uuid>,
+    Option<String>,
+    JsonValue,
+);
+
+#[derive(thiserror::Error, Debug)]
+pub enum AuditStoreError {
+    #[error(transparent)]
+    Pool(#[from] PoolError<sqlx::Error>),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+
+    #[error("malformed audit record `{0}`")]
+    Malformed(Uuid),
+}
+
+pub struct PostgresAuditStore {
+    pool: SqlxPool<Postgres>,
+}
+
+impl PostgresAuditStore {
+    #[must_use]
+    pub const fn new(pool: SqlxPool<Postgres>) -> Self {
+        Self {
+            pool,
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "audit.append")]
+    async fn append_inner(
+        &self,
+        record: AuditRecord,
+    ) -> Result<(), AuditStoreError> {
+        let mut connection = self.pool.get_guarded().await?;
+        let (target_kind, target_id) = record
+            .target
+            .map(|target| (target.kind, target.id))
+            .unzip();
+
+        sqlx::query(
+            "INSERT INTO audit_log \
+             (id, occurred_at, actor_kind, actor_id, action, target_kind, \
+              target_id, outcome, request_id, ip, details) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10::inet, $11)",
+        )
+        .bind(record.id.value)
+        .bind(record.occurred_at)
+        .bind(record.actor.kind())
+        .bind(record.actor.id())
+        .bind(record.action)
+        .bind(target_kind)
+        .bind(target_id)
+        .bind(record.outcome.as_str())
+        .bind(record.request_id)
+        .bind(record.ip.map(|ip| ip.to_string()))
+        .bind(record.details)
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, name = "audit.query")]
+    async fn query_inner(
+        &self,
+        filter: AuditFilter,
+        request: PageRequest,
+    ) -> Result<Page<AuditRecord>, AuditStoreError> {
+        let mut connection = self.pool.get_guarded().await?;
+        let mut query = KeysetQuery::new(
+            "SELECT id, occurred_at, actor_kind, actor_id, action, \
+                    target_kind, target_id, outcome, request_id, host(ip), \
+                    details \
+             FROM audit_log",
+        );
+
+        if let Some(actor) = filter.actor {
+            query.filter("actor_id = ", actor);
+        }
+        if let Some(action) = filter.action {
+            query.filter("action = ", action);
+        }
+        if let Some(outcome) = filter.outcome {
+            query.filter("outcome = ", outcome.as_str());
+        }
+
+        let records = query
+            .page("id", &request)
+            .build_query_as::<AuditRow>()
+            .fetch_all(&mut *connection)
+            .await?
+            .into_iter()
+            .map(decode)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Page::from_keyset(&request, records, |record: &AuditRecord| {
+            record.id
+        }))
+    }
+}
+
+impl AuditStore for PostgresAuditStore {
+    fn append(&self, record: AuditRecord) -> BoxFuture<'_, Result<(), String>> {
+        self.append_inner(record)
+            .map(|result| result.map_err(|error| error.to_string()))
+            .boxed()
+    }
+
+    fn query(
+        &self,
+        filter: AuditFilter,
+        request: PageRequest,
+    ) -> BoxFuture<'_, Result<Page<AuditRecord>, String>> {
+        self.query_inner(filter, request)
+            .map(|result| result.map_err(|error| error.to_string()))
+            .boxed()
+    }
+}
+
+fn decode(row: AuditRow) -> Result<AuditRecord, AuditStoreError> {
+    let (
+        id,
+        occurred_at,
+        actor_kind,
+        actor_id,
+        action,
+        target_kind,
+        target_id,
+        outcome,
+        request_id,
+        ip,
+        details,
+    ) = row;
+
+    let actor = match (actor_kind.as_str(), actor_id) {
+        ("anonymous", None) => Actor::Anonymous,
+        ("system", None) => Actor::System,
+        ("user", Some(id)) => Actor::User(id),
+        _ => return Err(AuditStoreError::Malformed(id)),
+    };
+
+    let outcome = match outcome.as_str() {
+        "success" => AuditOutcome::Success,
+        "failure" => AuditOutcome::Failure,
+        _ => return Err(AuditStoreError::Malformed(id)),
+    };
+
+    let target = target_kind.zip(target_id).map(|(kind, id)| AuditTarget {
+        kind,
+        id,
+    });
+
+    Ok(AuditRecord {
+        id: Id::new(id),
+        occurred_at,
+        actor,
+        action,
+        target,
+        outcome,
+        request_id,
+        ip: ip.and_then(|ip| ip.parse::<IpAddr>().ok()),
+        details,
+    })
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use mobc_sqlx::sqlx::{
+    SqlSafeStr as _,
+    migrate::{Migration, MigrationType, Migrator},
+};
+
+pub mod audit;
+pub mod outbox;
+pub mod pagination;
+pub mod queue;
+
+fn migrator(
+    version: i64,
+    description: &'static str,
+    up: &'static str,
+    down: &'static str,
+) -> Migrator {
+    let mut migrator = Migrator::with_migrations(vec![
+        Migration::new(
+            version,
+            Cow::Borrowed(description),
+            MigrationType::ReversibleUp,
+            up.into_sql_str(),
+            false,
+        ),
+        Migration::new(
+            version,
+            Cow::Borrowed(description),
+            MigrationType::ReversibleDown,
+            down.into_sql_str(),
+            false,
+        ),
+    ]);
+    migrator.set_ignore_missing(true);
+    migrator
+}
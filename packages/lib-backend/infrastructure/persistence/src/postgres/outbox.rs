@@ -0,0 +1,406 @@
+use std::{
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
+
+use application::{
+    outbox::{JobEntry, Outbox, OutboxEntry},
+    retry::RetryPolicy,
+    shutdown::Shutdown,
+};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use mobc_sqlx::{
+    mobc::async_trait,
+    sqlx::{
+        self, Postgres,
+        migrate::Migrator,
+        types::{
+            JsonValue, Uuid,
+            chrono::{DateTime, Utc},
+        },
+    },
+};
+use serde::de::DeserializeOwned;
+
+use crate::{PoolError, SqlxPool, transaction::AcquireError};
+
+#[expect(
+    clippy::unreadable_literal,
+    reason = "migration versions are timestamps"
+)]
+const OUTBOX_MIGRATION_VERSION: i64 = 20260201000000;
+
+pub static OUTBOX_MIGRATOR: LazyLock<Migrator> = LazyLock::new(|| {
+    super::migrator(
+        OUTBOX_MIGRATION_VERSION,
+        "outbox",
+        include_str!("../../migrations/20260201000000_outbox.up.sql"),
+        include_str!("../../migrations/20260201000000_outbox.down.sql"),
+    )
+});
+
+type OutboxRow = (Uuid, String, String, JsonValue, i32, DateTime<Utc>);
+
+pub const DEFAULT_BATCH_SIZE: i64 = 100;
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+pub const DEFAULT_MAX_ATTEMPTS: i32 = 10;
+pub const DEFAULT_CLAIM_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(thiserror::Error, Debug)]
+pub enum OutboxError {
+    #[error(transparent)]
+    Acquire(#[from] AcquireError),
+
+    #[error(transparent)]
+    Pool(#[from] PoolError<sqlx::Error>),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+pub struct PostgresOutbox {
+    pool: SqlxPool<Postgres>,
+}
+
+impl PostgresOutbox {
+    #[must_use]
+    pub const fn new(pool: SqlxPool<Postgres>) -> Self {
+        Self {
+            pool,
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "outbox.append")]
+    async fn append_inner(
+        &self,
+        entry: OutboxEntry,
+    ) -> Result<(), OutboxError> {
+        let mut connection = self.pool.acquire().await?;
+
+        sqlx::query(
+            "INSERT INTO outbox (id, aggregate_id, event_type, payload) \
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(Uuid::now_v7())
+        .bind(entry.aggregate_id)
+        .bind(entry.event_type)
+        .bind(entry.payload)
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, name = "job_queue.enqueue")]
+    async fn enqueue_job_inner(
+        &self,
+        job: JobEntry,
+    ) -> Result<(), OutboxError> {
+        let mut connection = self.pool.acquire().await?;
+
+        sqlx::query(
+            "INSERT INTO job_queue \
+                 (id, queue, job_type, payload, max_attempts, available_at) \
+             VALUES ($1, $2, $3, $4, $5, now() + $6)",
+        )
+        .bind(Uuid::now_v7())
+        .bind(job.queue)
+        .bind(job.job_type)
+        .bind(job.payload)
+        .bind(job.max_attempts)
+        .bind(job.delay)
+        .execute(&mut *connection)
+        .await?;
+
+        metrics::counter!(
+            "job_queue_enqueued_total",
+            "queue" => job.queue,
+            "job" => job.job_type
+        )
+        .increment(1);
+
+        Ok(())
+    }
+}
+
+impl Outbox for PostgresOutbox {
+    fn append(&self, entry: OutboxEntry) -> BoxFuture<'_, Result<(), String>> {
+        self.append_inner(entry)
+            .map(|result| result.map_err(|error| error.to_string()))
+            .boxed()
+    }
+
+    fn enqueue_job(&self, job: JobEntry) -> BoxFuture<'_, Result<(), String>> {
+        self.enqueue_job_inner(job)
+            .map(|result| result.map_err(|error| error.to_string()))
+            .boxed()
+    }
+}
+
+#[tracing::instrument(skip_all, name = "outbox.purge")]
+pub async fn purge_delivered(
+    pool: &SqlxPool<Postgres>,
+    older_than: Duration,
+) -> Result<u64, OutboxError> {
+    let mut connection = pool.get_guarded().await?;
+
+    let purged = sqlx::query(
+        "DELETE FROM outbox \
+         WHERE delivered_at IS NOT NULL \
+           AND delivered_at < now() - $1",
+    )
+    .bind(older_than)
+    .execute(&mut *connection)
+    .await?
+    .rows_affected();
+
+    metrics::counter!("outbox_purged_total").increment(purged);
+
+    Ok(purged)
+}
+
+#[derive(Debug)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub aggregate_id: String,
+    pub event_type: String,
+    pub payload: JsonValue,
+    pub attempts: i32,
+}
+
+impl OutboxMessage {
+    pub fn decode<E>(&self) -> Result<E, serde_json::Error>
+    where
+        E: DeserializeOwned,
+    {
+        E::deserialize(&self.payload)
+    }
+}
+
+pub type SinkResult = Result<(), String>;
+
+#[async_trait]
+pub trait OutboxSink: Send + Sync {
+    async fn deliver(&self, message: &OutboxMessage) -> SinkResult;
+}
+
+pub struct OutboxRelay {
+    pool: SqlxPool<Postgres>,
+    sinks: Vec<Arc<dyn OutboxSink>>,
+    batch_size: i64,
+    poll_interval: Duration,
+    claim_timeout: Duration,
+    max_attempts: i32,
+    backoff: RetryPolicy,
+}
+
+impl OutboxRelay {
+    #[must_use]
+    pub fn new(pool: SqlxPool<Postgres>) -> Self {
+        Self {
+            pool,
+            sinks: Vec::new(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            claim_timeout: DEFAULT_CLAIM_TIMEOUT,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            backoff: RetryPolicy::new()
+                .initial_delay(Duration::from_secs(1))
+                .max_delay(Duration::from_secs(300)),
+        }
+    }
+
+    #[must_use]
+    pub fn sink<S>(mut self, sink: S) -> Self
+    where
+        S: OutboxSink + 'static,
+    {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+
+    #[must_use]
+    pub const fn batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn claim_timeout(mut self, claim_timeout: Duration) -> Self {
+        self.claim_timeout = claim_timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    #[must_use]
+    pub const fn backoff(mut self, backoff: RetryPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    pub async fn run(self, shutdown: Shutdown) {
+        while !shutdown.is_triggered() {
+            match self.relay_batch().await {
+                Ok(relayed) if relayed > 0 => continue,
+                Ok(_) => {},
+                Err(error) => {
+                    tracing::error!(error = %error, "failed to relay outbox");
+                },
+            }
+
+            tokio::select! {
+                () = tokio::time::sleep(self.poll_interval) => {},
+                () = shutdown.triggered() => {},
+            }
+        }
+
+        tracing::info!("outbox relay stopped");
+    }
+
+    #[tracing::instrument(skip_all, name = "outbox.relay")]
+    async fn relay_batch(&self) -> Result<usize, OutboxError> {
+        let mut messages = self.claim().await?;
+        messages.sort_by_key(|(.., created_at)| *created_at);
+
+        let relayed = messages.len();
+
+        for (id, aggregate_id, event_type, payload, attempts, _) in messages {
+            let message = OutboxMessage {
+                id,
+                aggregate_id,
+                event_type,
+                payload,
+                attempts,
+            };
+
+            match self.deliver(&message).await {
+                Ok(()) => self.mark_delivered(&message).await?,
+                Err(error) => self.mark_failed(&message, &error).await?,
+            }
+        }
+
+        Ok(relayed)
+    }
+
+    async fn claim(&self) -> Result<Vec<OutboxRow>, OutboxError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        let rows = sqlx::query_as::<_, OutboxRow>(
+            "WITH claimed AS ( \
+                 SELECT id FROM outbox \
+                 WHERE delivered_at IS NULL \
+                   AND available_at <= now() \
+                   AND attempts < $2 \
+                   AND NOT EXISTS ( \
+                       SELECT 1 FROM outbox AS earlier \
+                       WHERE earlier.aggregate_id = outbox.aggregate_id \
+                         AND earlier.delivered_at IS NULL \
+                         AND earlier.attempts < $2 \
+                         AND (earlier.created_at, earlier.id) \
+                             < (outbox.created_at, outbox.id) \
+                   ) \
+                 ORDER BY created_at \
+                 LIMIT $1 \
+                 FOR UPDATE SKIP LOCKED \
+             ) \
+             UPDATE outbox \
+             SET available_at = now() + $3 \
+             FROM claimed \
+             WHERE outbox.id = claimed.id \
+             RETURNING outbox.id, outbox.aggregate_id, outbox.event_type, \
+                       outbox.payload, outbox.attempts, outbox.created_at",
+        )
+        .bind(self.batch_size)
+        .bind(self.max_attempts)
+        .bind(self.claim_timeout)
+        .fetch_all(&mut *connection)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn mark_delivered(
+        &self,
+        message: &OutboxMessage,
+    ) -> Result<(), OutboxError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE outbox \
+             SET delivered_at = now(), last_error = NULL \
+             WHERE id = $1",
+        )
+        .bind(message.id)
+        .execute(&mut *connection)
+        .await?;
+
+        metrics::counter!(
+            "outbox_delivered_total",
+            "event" => message.event_type.clone()
+        )
+        .increment(1);
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        message: &OutboxMessage,
+        error: &str,
+    ) -> Result<(), OutboxError> {
+        let attempts = message.attempts.saturating_add(1);
+        let delay = u32::try_from(attempts).map_or(self.poll_interval, |n| {
+            self.backoff.backoff(n)
+        });
+
+        tracing::warn!(
+            id = %message.id,
+            event = %message.event_type,
+            attempts,
+            error = %error,
+            "failed to deliver outbox message"
+        );
+
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE outbox \
+             SET attempts = $2, last_error = $3, \
+                 available_at = now() + $4 \
+             WHERE id = $1",
+        )
+        .bind(message.id)
+        .bind(attempts)
+        .bind(error)
+        .bind(delay)
+        .execute(&mut *connection)
+        .await?;
+
+        metrics::counter!(
+            "outbox_delivery_failures_total",
+            "event" => message.event_type.clone()
+        )
+        .increment(1);
+
+        Ok(())
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> SinkResult {
+        for sink in &self.sinks {
+            sink.deliver(message).await?;
+        }
+
+        Ok(())
+    }
+}
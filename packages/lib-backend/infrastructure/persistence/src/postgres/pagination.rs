@@ -0,0 +1,56 @@
+use domain::pagination::{Direction, PageRequest};
+use mobc_sqlx::sqlx::{Encode, Postgres, QueryBuilder, Type};
+
+pub struct KeysetQuery<'args> {
+    builder: QueryBuilder<'args, Postgres>,
+    filtered: bool,
+}
+
+impl<'args> KeysetQuery<'args> {
+    #[must_use]
+    pub fn new(select: &str) -> Self {
+        Self {
+            builder: QueryBuilder::new(select.trim_end()),
+            filtered: false,
+        }
+    }
+
+    pub fn filter<T>(&mut self, condition: &'static str, value: T) -> &mut Self
+    where
+        T: 'args + Encode<'args, Postgres> + Type<Postgres>,
+    {
+        self.push_conjunction().push(condition).push_bind(value);
+        self
+    }
+
+    pub fn page(
+        &mut self,
+        column: &'static str,
+        request: &PageRequest,
+    ) -> &mut QueryBuilder<'args, Postgres> {
+        let (comparison, order) = match request.direction() {
+            Direction::Forward => (" > ", " ASC"),
+            Direction::Backward => (" < ", " DESC"),
+        };
+
+        if let Some(cursor) = request.cursor {
+            self.push_conjunction()
+                .push(column)
+                .push(comparison)
+                .push_bind(cursor.position);
+        }
+
+        self.builder
+            .push(" ORDER BY ")
+            .push(column)
+            .push(order)
+            .push(" LIMIT ")
+            .push_bind(request.limit.fetch_size())
+    }
+
+    fn push_conjunction(&mut self) -> &mut QueryBuilder<'args, Postgres> {
+        let keyword = if self.filtered { " AND " } else { " WHERE " };
+        self.filtered = true;
+        self.builder.push(keyword)
+    }
+}
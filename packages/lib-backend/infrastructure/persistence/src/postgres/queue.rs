@@ -0,0 +1,478 @@
+use std::{collections::HashMap, sync::LazyLock, time::Duration};
+
+pub use application::outbox::{DEFAULT_MAX_ATTEMPTS, DEFAULT_QUEUE, QueuedJob};
+use application::{retry::RetryPolicy, shutdown::Shutdown};
+use futures_util::future;
+use mobc_sqlx::{
+    mobc::async_trait,
+    sqlx::{
+        self, Postgres,
+        migrate::Migrator,
+        types::{JsonValue, Uuid},
+    },
+};
+use tap::Pipe as _;
+use tracing::Instrument as _;
+
+use crate::{PoolError, SqlxPool};
+
+#[expect(
+    clippy::unreadable_literal,
+    reason = "migration versions are timestamps"
+)]
+const JOB_QUEUE_MIGRATION_VERSION: i64 = 20260301000000;
+
+pub static JOB_QUEUE_MIGRATOR: LazyLock<Migrator> = LazyLock::new(|| {
+    super::migrator(
+        JOB_QUEUE_MIGRATION_VERSION,
+        "job_queue",
+        include_str!("../../migrations/20260301000000_job_queue.up.sql"),
+        include_str!("../../migrations/20260301000000_job_queue.down.sql"),
+    )
+});
+
+type ClaimedRow = (Uuid, Uuid, String, JsonValue, i32, i32);
+
+pub const DEFAULT_CONCURRENCY: usize = 4;
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+pub const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(300);
+pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(60);
+
+const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(thiserror::Error, Debug)]
+pub enum JobQueueError {
+    #[error(transparent)]
+    Pool(#[from] PoolError<sqlx::Error>),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+pub type JobResult = Result<(), String>;
+
+#[derive(Clone, Copy, Debug)]
+struct Lease {
+    id: Uuid,
+    token: Uuid,
+}
+
+#[async_trait]
+pub trait JobHandler<D>: Send + Sync
+where
+    D: Sync,
+{
+    type Job: QueuedJob;
+
+    async fn handle(&self, job: Self::Job, deps: &D) -> JobResult;
+}
+
+#[async_trait]
+trait ErasedJobHandler<D>: Send + Sync
+where
+    D: Sync,
+{
+    async fn handle(&self, payload: JsonValue, deps: &D) -> JobResult;
+}
+
+struct TypedJobHandler<H>(H);
+
+#[async_trait]
+impl<D, H> ErasedJobHandler<D> for TypedJobHandler<H>
+where
+    D: Sync,
+    H: JobHandler<D>,
+{
+    async fn handle(&self, payload: JsonValue, deps: &D) -> JobResult {
+        let job = serde_json::from_value::<H::Job>(payload)
+            .map_err(|error| error.to_string())?;
+
+        self.0.handle(job, deps).await
+    }
+}
+
+pub struct JobWorker<D> {
+    pool: SqlxPool<Postgres>,
+    queue: &'static str,
+    handlers: HashMap<&'static str, Box<dyn ErasedJobHandler<D>>>,
+    concurrency: usize,
+    poll_interval: Duration,
+    visibility_timeout: Duration,
+    heartbeat_interval: Duration,
+    backoff: RetryPolicy,
+}
+
+impl<D> JobWorker<D>
+where
+    D: Sync,
+{
+    #[must_use]
+    pub fn new(pool: SqlxPool<Postgres>) -> Self {
+        Self {
+            pool,
+            queue: DEFAULT_QUEUE,
+            handlers: HashMap::new(),
+            concurrency: DEFAULT_CONCURRENCY,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            backoff: RetryPolicy::new()
+                .initial_delay(Duration::from_secs(5))
+                .max_delay(Duration::from_secs(600)),
+        }
+    }
+
+    #[must_use]
+    pub const fn queue(mut self, queue: &'static str) -> Self {
+        self.queue = queue;
+        self
+    }
+
+    #[must_use]
+    pub fn handler<H>(mut self, handler: H) -> Self
+    where
+        H: JobHandler<D> + 'static,
+    {
+        self.handlers
+            .insert(H::Job::NAME, Box::new(TypedJobHandler(handler)));
+        self
+    }
+
+    #[must_use]
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    #[must_use]
+    pub const fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn visibility_timeout(
+        mut self,
+        visibility_timeout: Duration,
+    ) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn heartbeat_interval(
+        mut self,
+        heartbeat_interval: Duration,
+    ) -> Self {
+        self.heartbeat_interval = heartbeat_interval;
+        self
+    }
+
+    #[must_use]
+    pub const fn backoff(mut self, backoff: RetryPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    pub async fn run(self, deps: &D, shutdown: Shutdown) {
+        future::join_all(
+            (0..self.concurrency).map(|_| self.work(deps, &shutdown)),
+        )
+        .await;
+
+        tracing::info!(queue = self.queue, "job worker stopped");
+    }
+
+    async fn work(&self, deps: &D, shutdown: &Shutdown) {
+        while !shutdown.is_triggered() {
+            match self.process_next(deps).await {
+                Ok(true) => continue,
+                Ok(false) => {},
+                Err(error) => {
+                    tracing::error!(
+                        queue = self.queue,
+                        error = %error,
+                        "failed to process job"
+                    );
+                },
+            }
+
+            tokio::select! {
+                () = tokio::time::sleep(self.poll_interval) => {},
+                () = shutdown.triggered() => {},
+            }
+        }
+    }
+
+    async fn process_next(&self, deps: &D) -> Result<bool, JobQueueError> {
+        self.bury_expired().await?;
+
+        let Some((id, token, job_type, payload, attempts, max_attempts)) =
+            self.claim().await?
+        else {
+            return Ok(false);
+        };
+        let lease = Lease {
+            id,
+            token,
+        };
+
+        let span = tracing::info_span!(
+            "job_queue.handle",
+            queue = self.queue,
+            job = %job_type,
+            %id,
+            attempts,
+        );
+
+        let result = match self.handlers.get(job_type.as_str()) {
+            Some(handler) => {
+                let handle =
+                    handler.handle(payload, deps).instrument(span.clone());
+
+                tokio::select! {
+                    result = handle => Some(result),
+                    () = self.heartbeat(lease) => None,
+                }
+            },
+            None => {
+                Some(Err(format!("no handler registered for `{job_type}`")))
+            },
+        };
+
+        let outcome = match result {
+            None => "lease_lost",
+            Some(Ok(())) => {
+                if self.complete(lease).await? {
+                    "success"
+                } else {
+                    "lease_lost"
+                }
+            },
+            Some(Err(error)) if attempts >= max_attempts => {
+                span.in_scope(|| {
+                    tracing::error!(
+                        error = %error,
+                        "job failed, moving to dead-letter"
+                    );
+                });
+                if self.bury(lease, &error).await? {
+                    "dead"
+                } else {
+                    "lease_lost"
+                }
+            },
+            Some(Err(error)) => {
+                let delay = u32::try_from(attempts)
+                    .map_or(self.poll_interval, |attempt| {
+                        self.backoff.backoff(attempt)
+                    });
+
+                span.in_scope(|| {
+                    tracing::warn!(error = %error, "job failed, retrying");
+                });
+                if self.reschedule(lease, &error, delay).await? {
+                    "failure"
+                } else {
+                    "lease_lost"
+                }
+            },
+        };
+
+        if outcome == "lease_lost" {
+            span.in_scope(|| {
+                tracing::warn!("job lease lost, it was reclaimed by a worker");
+            });
+        }
+
+        metrics::counter!(
+            "job_queue_processed_total",
+            "queue" => self.queue,
+            "job" => job_type,
+            "outcome" => outcome
+        )
+        .increment(1);
+
+        Ok(true)
+    }
+
+    async fn heartbeat(&self, lease: Lease) {
+        let period = self
+            .visibility_timeout
+            .checked_div(2)
+            .map_or(self.heartbeat_interval, |half| {
+                half.min(self.heartbeat_interval)
+            })
+            .max(MIN_HEARTBEAT_INTERVAL);
+
+        let mut interval = tokio::time::interval(period);
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            match self.extend(lease).await {
+                Ok(true) => {},
+                Ok(false) => return,
+                Err(error) => {
+                    tracing::warn!(
+                        queue = self.queue,
+                        id = %lease.id,
+                        error = %error,
+                        "failed to extend job lease"
+                    );
+                },
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, name = "job_queue.claim")]
+    async fn claim(&self) -> Result<Option<ClaimedRow>, JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query_as::<_, ClaimedRow>(
+            "UPDATE job_queue \
+             SET status = 'running', \
+                 attempts = attempts + 1, \
+                 lease_token = $3, \
+                 locked_until = now() + $2 \
+             WHERE id = ( \
+                 SELECT id FROM job_queue \
+                 WHERE queue = $1 \
+                   AND ((status = 'pending' AND available_at <= now()) \
+                     OR (status = 'running' AND locked_until < now() \
+                         AND attempts < max_attempts)) \
+                 ORDER BY available_at \
+                 LIMIT 1 \
+                 FOR UPDATE SKIP LOCKED \
+             ) \
+             RETURNING id, lease_token, job_type, payload, attempts, \
+                       max_attempts",
+        )
+        .bind(self.queue)
+        .bind(self.visibility_timeout)
+        .bind(Uuid::now_v7())
+        .fetch_optional(&mut *connection)
+        .await?
+        .pipe(Ok)
+    }
+
+    #[tracing::instrument(skip_all, name = "job_queue.bury_expired")]
+    async fn bury_expired(&self) -> Result<(), JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        let buried = sqlx::query(
+            "UPDATE job_queue \
+             SET status = 'dead', \
+                 last_error = 'lease expired on the final attempt', \
+                 locked_until = NULL, lease_token = NULL \
+             WHERE queue = $1 \
+               AND status = 'running' \
+               AND locked_until < now() \
+               AND attempts >= max_attempts",
+        )
+        .bind(self.queue)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+
+        if buried > 0 {
+            tracing::error!(
+                queue = self.queue,
+                buried,
+                "jobs lost their lease on the final attempt, \
+                 moving to dead-letter"
+            );
+            metrics::counter!(
+                "job_queue_processed_total",
+                "queue" => self.queue,
+                "job" => "unknown",
+                "outcome" => "dead"
+            )
+            .increment(buried);
+        }
+
+        Ok(())
+    }
+
+    async fn extend(&self, lease: Lease) -> Result<bool, JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE job_queue \
+             SET locked_until = now() + $3 \
+             WHERE id = $1 AND lease_token = $2 AND status = 'running'",
+        )
+        .bind(lease.id)
+        .bind(lease.token)
+        .bind(self.visibility_timeout)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected()
+        .pipe(|affected| Ok(affected > 0))
+    }
+
+    async fn complete(&self, lease: Lease) -> Result<bool, JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE job_queue \
+             SET status = 'completed', completed_at = now(), \
+                 locked_until = NULL, lease_token = NULL, last_error = NULL \
+             WHERE id = $1 AND lease_token = $2 AND status = 'running'",
+        )
+        .bind(lease.id)
+        .bind(lease.token)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected()
+        .pipe(|affected| Ok(affected > 0))
+    }
+
+    async fn reschedule(
+        &self,
+        lease: Lease,
+        error: &str,
+        delay: Duration,
+    ) -> Result<bool, JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE job_queue \
+             SET status = 'pending', last_error = $3, \
+                 available_at = now() + $4, \
+                 locked_until = NULL, lease_token = NULL \
+             WHERE id = $1 AND lease_token = $2 AND status = 'running'",
+        )
+        .bind(lease.id)
+        .bind(lease.token)
+        .bind(error)
+        .bind(delay)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected()
+        .pipe(|affected| Ok(affected > 0))
+    }
+
+    async fn bury(
+        &self,
+        lease: Lease,
+        error: &str,
+    ) -> Result<bool, JobQueueError> {
+        let mut connection = self.pool.get_guarded().await?;
+
+        sqlx::query(
+            "UPDATE job_queue \
+             SET status = 'dead', last_error = $3, \
+                 locked_until = NULL, lease_token = NULL \
+             WHERE id = $1 AND lease_token = $2 AND status = 'running'",
+        )
+        .bind(lease.id)
+        .bind(lease.token)
+        .bind(error)
+        .execute(&mut *connection)
+        .await?
+        .rows_affected()
+        .pipe(|affected| Ok(affected > 0))
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use application::rate_limit::{Decision, Quota, RateLimitStore};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use redis_driver::cmd;
+use uuid::Uuid;
+
+use crate::{RedisPool, redis::Namespace};
+
+const SLIDING_WINDOW_SCRIPT: &str = r"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local limit = tonumber(ARGV[3])
+
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+
+local count = redis.call('ZCARD', KEYS[1])
+local allowed = 0
+if count < limit then
+    redis.call('ZADD', KEYS[1], now, ARGV[4])
+    count = count + 1
+    allowed = 1
+end
+redis.call('PEXPIRE', KEYS[1], window)
+
+local reset = 0
+local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+if oldest[2] then
+    reset = tonumber(oldest[2]) + window - now
+end
+
+return {allowed, count, reset}
+";
+
+pub struct RedisRateLimitStore {
+    pool: RedisPool,
+    namespace: Namespace,
+}
+
+impl RedisRateLimitStore {
+    #[must_use]
+    pub fn new(pool: RedisPool, namespace: &Namespace) -> Self {
+        Self {
+            pool,
+            namespace: namespace.nest("rate_limit"),
+        }
+    }
+
+    async fn hit_inner(
+        &self,
+        key: &str,
+        quota: Quota,
+    ) -> Result<Decision, String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| error.to_string())?;
+        let now_ms = u64::try_from(now.as_millis()).unwrap_or(u64::MAX);
+        let window_ms =
+            u64::try_from(quota.window.as_millis()).unwrap_or(u64::MAX).max(1);
+
+        let mut connection = self
+            .pool
+            .get_guarded()
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let (allowed, count, reset_ms) = cmd("EVAL")
+            .arg(SLIDING_WINDOW_SCRIPT)
+            .arg(1)
+            .arg(self.namespace.key(key))
+            .arg(now_ms)
+            .arg(window_ms)
+            .arg(quota.limit)
+            .arg(Uuid::now_v7().to_string())
+            .query_async::<(i64, i64, i64)>(&mut *connection)
+            .await
+            .map_err(|error| error.to_string())?;
+
+        let used = u32::try_from(count).unwrap_or(u32::MAX);
+
+        Ok(Decision {
+            allowed: allowed == 1,
+            limit: quota.limit,
+            remaining: quota.limit.saturating_sub(used),
+            reset_after: Duration::from_millis(
+                u64::try_from(reset_ms).unwrap_or_default(),
+            ),
+        })
+    }
+}
+
+impl RateLimitStore for RedisRateLimitStore {
+    fn hit<'a>(
+        &'a self,
+        key: &'a str,
+        quota: Quota,
+    ) -> BoxFuture<'a, Result<Decision, String>> {
+        self.hit_inner(key, quota).boxed()
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use application::{
+    di::Has,
+    transaction::{self, Transaction, TransactionError, TransactionManager},
+};
+use mobc::{Connection, Manager};
+use mobc_sqlx::{
+    SqlxConnectionManager,
+    sqlx::{Database, Executor, raw_sql},
+};
+use tap::Pipe as _;
+use tokio::sync::{Mutex, OwnedMappedMutexGuard, OwnedMutexGuard};
+
+use super::{Pool, PoolError};
+
+type SqlxManager<DB> = SqlxConnectionManager<DB>;
+
+type RawConnection<DB> = <SqlxManager<DB> as Manager>::Connection;
+
+pub struct TransactionSlot<DB>(Option<Connection<SqlxManager<DB>>>)
+where
+    DB: Database + Sync;
+
+impl<DB> Drop for TransactionSlot<DB>
+where
+    DB: Database + Sync,
+{
+    fn drop(&mut self) {
+        if let Some(connection) = self.0.take() {
+            tracing::warn!(
+                "transaction dropped without being finished, discarding its \
+                 connection"
+            );
+            drop(connection.into_inner());
+        }
+    }
+}
+
+pub struct SqlxTransaction<DB>
+where
+    DB: Database + Sync,
+{
+    slot: Arc<Mutex<TransactionSlot<DB>>>,
+}
+
+impl<DB> Clone for SqlxTransaction<DB>
+where
+    DB: Database + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            slot: Arc::clone(&self.slot),
+        }
+    }
+}
+
+impl<DB> SqlxTransaction<DB>
+where
+    DB: Database + Sync,
+    for<'c> &'c mut RawConnection<DB>: Executor<'c, Database = DB>,
+{
+    async fn finish(
+        &self,
+        statement: &'static str,
+    ) -> Result<(), mobc_sqlx::sqlx::Error> {
+        let Some(mut connection) = self.slot.lock().await.0.take() else {
+            return Ok(());
+        };
+
+        raw_sql(statement)
+            .execute(&mut *connection)
+            .await
+            .map(drop)
+    }
+}
+
+impl<DB> Transaction for SqlxTransaction<DB>
+where
+    DB: Database + Sync,
+    for<'c> &'c mut RawConnection<DB>: Executor<'c, Database = DB>,
+{
+    async fn commit(&self) -> Result<(), TransactionError> {
+        self.finish("COMMIT")
+            .await
+            .map_err(|error| TransactionError::Commit(error.to_string()))
+    }
+
+    async fn rollback(&self) -> Result<(), TransactionError> {
+        self.finish("ROLLBACK")
+            .await
+            .map_err(|error| TransactionError::Rollback(error.to_string()))
+    }
+}
+
+impl<DB> TransactionManager for Pool<SqlxManager<DB>>
+where
+    DB: Database + Sync,
+    for<'c> &'c mut RawConnection<DB>: Executor<'c, Database = DB>,
+{
+    type Transaction = SqlxTransaction<DB>;
+
+    async fn begin(&self) -> Result<Self::Transaction, TransactionError> {
+        let mut connection = self
+            .get_guarded()
+            .await
+            .map_err(|error| TransactionError::Begin(error.to_string()))?;
+
+        raw_sql("BEGIN")
+            .execute(&mut *connection)
+            .await
+            .map_err(|error| TransactionError::Begin(error.to_string()))?;
+
+        Ok(SqlxTransaction {
+            slot: Some(connection)
+                .pipe(TransactionSlot)
+                .pipe(Mutex::new)
+                .pipe(Arc::new),
+        })
+    }
+}
+
+pub enum SqlxConnection<DB>
+where
+    DB: Database + Sync,
+{
+    Pooled(Connection<SqlxManager<DB>>),
+    Transaction(
+        OwnedMappedMutexGuard<TransactionSlot<DB>, Connection<SqlxManager<DB>>>,
+    ),
+}
+
+impl<DB> Deref for SqlxConnection<DB>
+where
+    DB: Database + Sync,
+{
+    type Target = RawConnection<DB>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(connection) => connection,
+        }
+    }
+}
+
+impl<DB> DerefMut for SqlxConnection<DB>
+where
+    DB: Database + Sync,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(connection) => connection,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AcquireError {
+    #[error(transparent)]
+    Pool(#[from] PoolError<mobc_sqlx::sqlx::Error>),
+
+    #[error(transparent)]
+    Transaction(#[from] TransactionError),
+}
+
+pub trait HasSqlxConnectionExt<DB>
+where
+    DB: Database + Sync,
+{
+    fn acquire(
+        &self,
+    ) -> impl Future<Output = Result<SqlxConnection<DB>, AcquireError>>;
+}
+
+impl<D, DB> HasSqlxConnectionExt<DB> for D
+where
+    D: Has<Pool<SqlxManager<DB>>>,
+    DB: Database + Sync,
+    for<'c> &'c mut RawConnection<DB>: Executor<'c, Database = DB>,
+{
+    async fn acquire(&self) -> Result<SqlxConnection<DB>, AcquireError> {
+        self.get_dependency().acquire().await
+    }
+}
+
+impl<DB> Pool<SqlxManager<DB>>
+where
+    DB: Database + Sync,
+    for<'c> &'c mut RawConnection<DB>: Executor<'c, Database = DB>,
+{
+    pub async fn acquire(&self) -> Result<SqlxConnection<DB>, AcquireError> {
+        let Some(current) = transaction::current::<SqlxTransaction<DB>>()
+        else {
+            return self
+                .get_guarded()
+                .await?
+                .pipe(SqlxConnection::Pooled)
+                .pipe(Ok);
+        };
+
+        current
+            .slot
+            .lock_owned()
+            .await
+            .pipe(|slot| {
+                OwnedMutexGuard::try_map(slot, |slot| slot.0.as_mut())
+            })
+            .map(SqlxConnection::Transaction)
+            .map_err(|_| TransactionError::Finished.into())
+    }
+}
@@ -0,0 +1,33 @@
+use application::audit::AuditContext;
+use tracing::Span;
+use tracing_subscriber::{Registry, registry::LookupSpan as _};
+
+use crate::errors::RequestMeta;
+
+#[must_use]
+pub fn request_context() -> AuditContext {
+    let mut context = AuditContext::default();
+
+    Span::current().with_subscriber(|(id, subscriber)| {
+        let Some(registry) = subscriber.downcast_ref::<Registry>() else {
+            return;
+        };
+
+        let meta = registry.span(id).and_then(|span_ref| {
+            span_ref.scope().find_map(|span| {
+                span.extensions().get::<RequestMeta>().map(|meta| {
+                    (meta.request_id, meta.client_ip)
+                })
+            })
+        });
+
+        if let Some((request_id, ip)) = meta {
+            context = AuditContext {
+                request_id,
+                ip,
+            };
+        }
+    });
+
+    context
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+pub use application::authorization::Permission;
+use application::{
+    authorization::{
+        AuthorizationError, HasAuthorizationExt as _, Policy, Principal, Scope,
+    },
+    di::Has,
+};
+use axum::{
+    extract::FromRequestParts,
+    http::{StatusCode, request::Parts},
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+#[cfg(feature = "openapi")]
+use utoipa::openapi::{path::Paths, security::SecurityRequirement};
+
+use crate::errors::JsonError;
+
+pub trait RequiredPermission {
+    const PERMISSION: Permission;
+}
+
+#[macro_export]
+macro_rules! required_permission {
+    ($(#[$meta: meta])* $vis: vis $name: ident = $permission: expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name;
+
+        impl $crate::authorization::RequiredPermission for $name {
+            const PERMISSION: $crate::authorization::Permission = $permission;
+        }
+    };
+}
+
+#[derive(Debug)]
+pub struct Authorized<P, R> {
+    pub principal: P,
+    pub scope: Scope,
+    permission: PhantomData<R>,
+}
+
+impl<S, P, R> FromRequestParts<S> for Authorized<P, R>
+where
+    S: Has<Policy> + Sync,
+    P: FromRequestParts<S> + Principal + Send,
+    R: RequiredPermission,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let principal = P::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let scope = state
+            .granted_scope(&principal, R::PERMISSION)
+            .map_err(|error| JsonError::from(error).into_response())?;
+
+        Ok(Self {
+            principal,
+            scope,
+            permission: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "openapi")]
+pub fn document_permission<R>(paths: &mut Paths, scheme: &str)
+where
+    R: RequiredPermission,
+{
+    let requirement =
+        SecurityRequirement::new(scheme, [R::PERMISSION.name()]);
+
+    for item in paths.paths.values_mut() {
+        [
+            &mut item.get,
+            &mut item.put,
+            &mut item.post,
+            &mut item.delete,
+            &mut item.options,
+            &mut item.head,
+            &mut item.patch,
+            &mut item.trace,
+        ]
+        .into_iter()
+        .flatten()
+        .for_each(|operation| {
+            operation.security = Some(vec![requirement.clone()]);
+        });
+    }
+}
+
+impl From<AuthorizationError> for JsonError {
+    fn from(error: AuthorizationError) -> Self {
+        Self::with_value_details(
+            StatusCode::FORBIDDEN,
+            "FORBIDDEN",
+            error,
+            json!({
+                "permission": error.permission()
+            }),
+        )
+    }
+}
@@ -1,8 +1,11 @@
+use std::net::IpAddr;
+
 use axum::{
     Json,
     http::{StatusCode, Uri},
     response::{IntoResponse, Response},
 };
+use application::clock::SharedClock;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use serde_json::Value;
@@ -26,6 +29,8 @@ pub mod validation;
 pub struct RequestMeta {
     pub http_route: Uri,
     pub request_id: Option<Uuid>,
+    pub client_ip: Option<IpAddr>,
+    pub clock: SharedClock,
 }
 
 #[derive(Serialize, Debug)]
@@ -72,6 +77,7 @@ impl JsonErrorStruct {
     {
         let mut http_route_option: Option<Uri> = None;
         let mut request_id_option: Option<Uuid> = None;
+        let mut timestamp_option: Option<DateTime<Utc>> = None;
 
         Span::current().with_subscriber(|(id, subscriber)| {
             if let Some(registry) =
@@ -81,6 +87,7 @@ impl JsonErrorStruct {
             {
                 http_route_option = Some(meta.http_route.clone());
                 request_id_option = meta.request_id;
+                timestamp_option = Some(meta.clock.now());
             }
         });
 
@@ -93,7 +100,7 @@ impl JsonErrorStruct {
             error_code,
             message: message.to_string(),
             trace_id: request_id,
-            timestamp: Utc::now(),
+            timestamp: timestamp_option.unwrap_or_else(Utc::now),
             path: http_route.to_string(),
         }
     }
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use application::feature_flag::{FeatureFlag, FeatureFlags};
+use axum::{
+    extract::{FromRequestParts, MatchedPath, Request, State},
+    http::{Method, request::Parts},
+    middleware::Next,
+    response::{IntoResponse as _, Response},
+};
+use futures_util::{FutureExt as _, future::BoxFuture};
+use uuid::Uuid;
+
+use crate::routes::fallback_404;
+
+pub trait FeatureFlagSubject {
+    fn feature_flag_subject(&self) -> Uuid;
+}
+
+type SubjectExtractor<M> = Arc<
+    dyn for<'a> Fn(&'a mut Parts, &'a M) -> BoxFuture<'a, Option<Uuid>>
+        + Send
+        + Sync,
+>;
+
+pub enum EvaluateFor<M> {
+    Anonymous,
+    Subject(SubjectExtractor<M>),
+}
+
+impl<M> EvaluateFor<M>
+where
+    M: Sync + 'static,
+{
+    #[must_use]
+    pub const fn anonymous() -> Self {
+        Self::Anonymous
+    }
+
+    #[must_use]
+    pub fn subject<E>() -> Self
+    where
+        E: FromRequestParts<M> + FeatureFlagSubject + 'static,
+    {
+        Self::Subject(Arc::new(extract_subject::<E, M>))
+    }
+}
+
+fn extract_subject<'a, E, M>(
+    parts: &'a mut Parts,
+    state: &'a M,
+) -> BoxFuture<'a, Option<Uuid>>
+where
+    E: FromRequestParts<M> + FeatureFlagSubject + 'static,
+    M: Sync,
+{
+    async move {
+        E::from_request_parts(parts, state)
+            .await
+            .ok()
+            .map(|subject| subject.feature_flag_subject())
+    }
+    .boxed()
+}
+
+struct Rule<M> {
+    method: Method,
+    path: &'static str,
+    flag: FeatureFlag,
+    subject: EvaluateFor<M>,
+}
+
+pub struct FeatureGate<M> {
+    flags: FeatureFlags,
+    rules: Vec<Rule<M>>,
+}
+
+impl<M> FeatureGate<M> {
+    #[must_use]
+    pub const fn new(flags: FeatureFlags) -> Self {
+        Self {
+            flags,
+            rules: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn route(
+        mut self,
+        method: Method,
+        path: &'static str,
+        flag: FeatureFlag,
+        subject: EvaluateFor<M>,
+    ) -> Self {
+        self.rules.push(Rule {
+            method,
+            path,
+            flag,
+            subject,
+        });
+        self
+    }
+
+    pub(crate) fn into_state(self, app: M) -> FeatureGateState<M> {
+        FeatureGateState {
+            flags: self.flags,
+            rules: self.rules.into(),
+            app,
+        }
+    }
+}
+
+pub(crate) struct FeatureGateState<M> {
+    flags: FeatureFlags,
+    rules: Arc<[Rule<M>]>,
+    app: M,
+}
+
+impl<M> Clone for FeatureGateState<M>
+where
+    M: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            flags: self.flags.clone(),
+            rules: Arc::clone(&self.rules),
+            app: self.app.clone(),
+        }
+    }
+}
+
+pub(crate) async fn hide_disabled<M>(
+    State(gate): State<FeatureGateState<M>>,
+    request: Request,
+    next: Next,
+) -> Response
+where
+    M: Clone + Send + Sync + 'static,
+{
+    let rule = request.extensions().get::<MatchedPath>().and_then(|path| {
+        gate.rules.iter().find(|rule| {
+            rule.method == request.method() && rule.path == path.as_str()
+        })
+    });
+
+    let Some(rule) = rule else {
+        return next.run(request).await;
+    };
+
+    let (mut parts, body) = request.into_parts();
+
+    let subject = match &rule.subject {
+        EvaluateFor::Anonymous => None,
+        EvaluateFor::Subject(extract) => extract(&mut parts, &gate.app).await,
+    };
+
+    if !gate.flags.evaluate(rule.flag, subject) {
+        return fallback_404().await.into_response();
+    }
+
+    next.run(Request::from_parts(parts, body)).await
+}
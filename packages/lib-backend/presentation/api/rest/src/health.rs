@@ -1,13 +1,21 @@
 use std::future::Future;
 
-use application::health::ReadinessReport;
+use application::{
+    authorization::{Policy, Principal},
+    di::Has,
+    health::{HistoryEntry, ReadinessReport, Status},
+};
 use axum::{
     Json,
-    extract::State,
+    extract::{FromRequestParts, State},
     http::StatusCode,
     response::{IntoResponse, Response},
     routing::{self, MethodRouter},
 };
+use serde::Serialize;
+use tap::Pipe as _;
+
+use crate::authorization::{Authorized, RequiredPermission};
 
 pub struct ReadinessResponse(pub ReadinessReport);
 
@@ -19,7 +27,7 @@ impl From<ReadinessReport> for ReadinessResponse {
 
 impl IntoResponse for ReadinessResponse {
     fn into_response(self) -> Response {
-        let status = if self.0.status.to_bool() {
+        let status = if self.0.status.is_available() {
             StatusCode::OK
         } else {
             StatusCode::SERVICE_UNAVAILABLE
@@ -49,6 +57,67 @@ where
     routing::get(readiness::<M>)
 }
 
+pub trait HealthHistorySource {
+    fn health_history(&self) -> Vec<HistoryEntry>;
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HistorySummary {
+    pub timestamp_ms: u64,
+    pub status: Status,
+}
+
+impl From<HistoryEntry> for HistorySummary {
+    fn from(entry: HistoryEntry) -> Self {
+        Self {
+            timestamp_ms: entry.timestamp_ms,
+            status: entry.status,
+        }
+    }
+}
+
+async fn health_history<M>(
+    State(modules): State<entrait::Impl<M>>,
+) -> Json<Vec<HistorySummary>>
+where
+    M: HealthHistorySource + Clone + Send + Sync + 'static,
+{
+    modules
+        .health_history()
+        .into_iter()
+        .map(HistorySummary::from)
+        .collect::<Vec<_>>()
+        .pipe(Json)
+}
+
+pub fn history<M>() -> MethodRouter<entrait::Impl<M>>
+where
+    M: HealthHistorySource + Clone + Send + Sync + 'static,
+{
+    routing::get(health_history::<M>)
+}
+
+async fn detailed_health_history<M, P, R>(
+    State(modules): State<entrait::Impl<M>>,
+    _: Authorized<P, R>,
+) -> Json<Vec<HistoryEntry>>
+where
+    M: HealthHistorySource + Clone + Send + Sync + 'static,
+{
+    modules.health_history().pipe(Json)
+}
+
+pub fn detailed_history<M, P, R>() -> MethodRouter<entrait::Impl<M>>
+where
+    M: HealthHistorySource + Clone + Send + Sync + 'static,
+    entrait::Impl<M>: Has<Policy>,
+    P: FromRequestParts<entrait::Impl<M>> + Principal + Send + 'static,
+    R: RequiredPermission + Send + 'static,
+{
+    routing::get(detailed_health_history::<M, P, R>)
+}
+
 pub fn live<S>() -> MethodRouter<S>
 where
     S: Clone + Send + Sync + 'static,
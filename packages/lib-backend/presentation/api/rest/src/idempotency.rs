@@ -0,0 +1,346 @@
+use std::{sync::Arc, time::Duration};
+
+use application::idempotency::{
+    IdempotencyRecord, IdempotencyStore, StoredResponse,
+};
+use axum::{
+    body::{Body, Bytes, to_bytes},
+    extract::{MatchedPath, Request, State},
+    http::{
+        HeaderName, HeaderValue, StatusCode,
+        header::{AUTHORIZATION, CACHE_CONTROL, RETRY_AFTER},
+        request::Parts,
+        uri::PathAndQuery,
+    },
+    middleware::Next,
+    response::{IntoResponse as _, Response},
+};
+use sha2::{Digest as _, Sha256};
+use uuid::Uuid;
+
+use crate::{errors::JsonError, rate_limit::client_ip};
+
+pub const HEADER: &str = "idempotency-key";
+pub const REPLAYED_HEADER: &str = "idempotent-replayed";
+
+pub const DEFAULT_TTL: Duration = Duration::from_secs(86_400);
+pub const DEFAULT_LOCK_TTL: Duration = Duration::from_secs(60);
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1_048_576;
+
+const MAX_KEY_LENGTH: usize = 255;
+
+#[derive(Clone)]
+pub struct Idempotency {
+    store: Arc<dyn IdempotencyStore>,
+    ttl: Duration,
+    lock_ttl: Duration,
+    max_body_bytes: usize,
+    trusted_proxy_hops: usize,
+}
+
+impl Idempotency {
+    pub fn new<S>(store: S) -> Self
+    where
+        S: IdempotencyStore + 'static,
+    {
+        Self {
+            store: Arc::new(store),
+            ttl: DEFAULT_TTL,
+            lock_ttl: DEFAULT_LOCK_TTL,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            trusted_proxy_hops: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    #[must_use]
+    pub const fn lock_ttl(mut self, lock_ttl: Duration) -> Self {
+        self.lock_ttl = lock_ttl;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    #[must_use]
+    pub const fn trusted_proxy_hops(mut self, hops: usize) -> Self {
+        self.trusted_proxy_hops = hops;
+        self
+    }
+
+    async fn handle(
+        &self,
+        key: &str,
+        request: Request,
+        next: Next,
+    ) -> Response {
+        let (parts, body) = request.into_parts();
+
+        let Ok(body) = to_bytes(body, self.max_body_bytes).await else {
+            return JsonError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "PAYLOAD_TOO_LARGE",
+                "request body is too large to be processed idempotently",
+            )
+            .into_response();
+        };
+
+        let scoped = scoped_key(&parts, key, self.trusted_proxy_hops);
+        let key = scoped.as_str();
+        let fingerprint = fingerprint(&parts, &body);
+        let owner = Uuid::new_v4().to_string();
+        let request = Request::from_parts(parts, Body::from(body));
+
+        match self
+            .store
+            .try_lock(key, &fingerprint, &owner, self.lock_ttl)
+            .await
+        {
+            Ok(None) => {},
+            Ok(Some(record)) if record.fingerprint() != fingerprint => {
+                return JsonError::new(
+                    StatusCode::CONFLICT,
+                    "IDEMPOTENCY_KEY_REUSED",
+                    "`idempotency-key` was already used for a different \
+                     request",
+                )
+                .into_response();
+            },
+            Ok(Some(IdempotencyRecord::InFlight {
+                ..
+            })) => {
+                let mut response = JsonError::new(
+                    StatusCode::CONFLICT,
+                    "IDEMPOTENCY_KEY_IN_USE",
+                    "a request with this `idempotency-key` is still being \
+                     processed",
+                )
+                .into_response();
+                response
+                    .headers_mut()
+                    .insert(RETRY_AFTER, HeaderValue::from_static("1"));
+                return response;
+            },
+            Ok(Some(IdempotencyRecord::Completed {
+                response,
+                ..
+            })) => {
+                tracing::debug!(key, "replaying stored response");
+                return replay(response);
+            },
+            Err(error) => {
+                tracing::warn!(
+                    key,
+                    error = %error,
+                    "idempotency store is unavailable, skipping"
+                );
+                return next.run(request).await;
+            },
+        }
+
+        let response = next.run(request).await;
+
+        if !response.status().is_success() {
+            self.release(key, &fingerprint, &owner).await;
+            return response;
+        }
+
+        if is_no_store(&response) {
+            let marker = StoredResponse {
+                status: response.status().as_u16(),
+                headers: vec![(
+                    CACHE_CONTROL.as_str().to_owned(),
+                    "no-store".to_owned(),
+                )],
+                body: Vec::new(),
+            };
+            self.complete(key, &fingerprint, marker).await;
+            return response;
+        }
+
+        let (parts, body) = response.into_parts();
+
+        let body = match to_bytes(body, usize::MAX).await {
+            Ok(body) => body,
+            Err(error) => {
+                tracing::error!(
+                    key,
+                    error = %error,
+                    "failed to buffer response body"
+                );
+                self.release(key, &fingerprint, &owner).await;
+                return JsonError::new(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "INTERNAL_ERROR",
+                    "failed to buffer response body",
+                )
+                .into_response();
+            },
+        };
+
+        let stored = StoredResponse {
+            status: parts.status.as_u16(),
+            headers: parts
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    let value = value.to_str().ok()?.to_owned();
+                    Some((name.as_str().to_owned(), value))
+                })
+                .collect(),
+            body: body.to_vec(),
+        };
+
+        self.complete(key, &fingerprint, stored).await;
+
+        Response::from_parts(parts, Body::from(body))
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        response: StoredResponse,
+    ) {
+        if let Err(error) = self
+            .store
+            .complete(key, fingerprint, response, self.ttl)
+            .await
+        {
+            tracing::warn!(
+                key,
+                error = %error,
+                "failed to store idempotent response"
+            );
+        }
+    }
+
+    async fn release(&self, key: &str, fingerprint: &str, owner: &str) {
+        if let Err(error) = self.store.release(key, fingerprint, owner).await {
+            tracing::warn!(
+                key,
+                error = %error,
+                "failed to release idempotency key"
+            );
+        }
+    }
+}
+
+pub async fn enforce(
+    State(idempotency): State<Option<Idempotency>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(idempotency) = idempotency else {
+        return next.run(request).await;
+    };
+
+    if request.method().is_safe() {
+        return next.run(request).await;
+    }
+
+    let Some(key) = request.headers().get(HEADER) else {
+        return next.run(request).await;
+    };
+
+    let Some(key) = key
+        .to_str()
+        .ok()
+        .filter(|key| !key.is_empty() && key.len() <= MAX_KEY_LENGTH)
+        .map(str::to_owned)
+    else {
+        return JsonError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "INVALID_IDEMPOTENCY_KEY",
+            "`idempotency-key` must be a non-empty string of at most 255 \
+             characters",
+        )
+        .into_response();
+    };
+
+    idempotency.handle(&key, request, next).await
+}
+
+// Keys are chosen by clients, so they are scoped to the route and the caller
+// to keep one client from replaying or blocking another client's request.
+fn scoped_key(parts: &Parts, key: &str, trusted_proxy_hops: usize) -> String {
+    let route = parts
+        .extensions
+        .get::<MatchedPath>()
+        .map_or_else(|| parts.uri.path(), MatchedPath::as_str);
+
+    let caller = parts.headers.get(AUTHORIZATION).map_or_else(
+        || {
+            client_ip(parts, trusted_proxy_hops).map_or_else(
+                || "anonymous".to_owned(),
+                |ip| format!("ip:{ip}"),
+            )
+        },
+        |authorization| {
+            format!("auth:{:x}", Sha256::digest(authorization.as_bytes()))
+        },
+    );
+
+    format!("{} {route}:{caller}:{key}", parts.method)
+}
+
+fn fingerprint(parts: &Parts, body: &Bytes) -> String {
+    let mut hasher = Sha256::new();
+
+    hasher.update(parts.method.as_str());
+    hasher.update(b"\n");
+    hasher.update(
+        parts
+            .uri
+            .path_and_query()
+            .map_or_else(|| parts.uri.path(), PathAndQuery::as_str),
+    );
+    hasher.update(b"\n");
+    if let Some(authorization) = parts.headers.get(AUTHORIZATION) {
+        hasher.update(authorization.as_bytes());
+    }
+    hasher.update(b"\n");
+    hasher.update(body);
+
+    format!("{:x}", hasher.finalize())
+}
+
+fn is_no_store(response: &Response) -> bool {
+    response
+        .headers()
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|directive| directive.trim().eq_ignore_ascii_case("no-store"))
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+
+    *response.status_mut() =
+        StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+
+    let headers = response.headers_mut();
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) =
+            (HeaderName::try_from(name), HeaderValue::try_from(value))
+        {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(
+        HeaderName::from_static(REPLAYED_HEADER),
+        HeaderValue::from_static("true"),
+    );
+
+    response
+}
@@ -6,12 +6,18 @@ pub use {
     serde_json, tap,
 };
 
+pub mod audit;
+pub mod authorization;
 pub mod errors;
 pub mod extract;
+pub mod feature_flag;
 pub mod health;
+pub mod idempotency;
 pub mod mask;
 pub mod negotiate;
+pub mod pagination;
 mod panic_handler;
+pub mod rate_limit;
 pub mod request_id;
 pub mod response;
 pub mod routes;
@@ -0,0 +1,80 @@
+use domain::pagination::{Cursor, Page, PageLimit, PageRequest};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "openapi")]
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    into_validators,
+    validation::{
+        LossyUserInput, UserInput, parseable::Parseable,
+        validator::ValidatorResult,
+    },
+};
+
+#[derive(Deserialize, Default, Debug)]
+#[cfg_attr(feature = "openapi", derive(IntoParams))]
+#[cfg_attr(feature = "openapi", into_params(parameter_in = Query))]
+pub struct PageQuery {
+    #[cfg_attr(
+        feature = "openapi",
+        param(value_type = Option<i64>, minimum = 1, maximum = 100)
+    )]
+    #[serde(default)]
+    pub limit: LossyUserInput<i64>,
+
+    #[cfg_attr(feature = "openapi", param(value_type = Option<String>))]
+    #[serde(default)]
+    pub cursor: UserInput<String>,
+}
+
+impl Parseable<PageRequest> for PageQuery {
+    fn parse(self) -> ValidatorResult<PageRequest> {
+        let (errors, (limit, cursor)) = into_validators!(
+            field!(self.limit, optional, "limit"),
+            field!(self.cursor, optional, "cursor"),
+        );
+
+        errors.into_result(|ok| PageRequest {
+            limit: limit.validated(ok).unwrap_or_default(),
+            cursor: cursor.validated(ok),
+        })
+    }
+}
+
+#[derive(Serialize, Default, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PageLinksDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(format = Uri))]
+    pub next: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "openapi", schema(format = Uri))]
+    pub prev: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PageDto<T> {
+    pub items: Vec<T>,
+    pub links: PageLinksDto,
+}
+
+impl<T> PageDto<T> {
+    pub fn from_page<S>(page: Page<S>, limit: PageLimit, path: &str) -> Self
+    where
+        S: Into<T>,
+    {
+        let link = |cursor: Cursor| {
+            format!("{path}?limit={}&cursor={cursor}", limit.get())
+        };
+
+        Self {
+            links: PageLinksDto {
+                next: page.next.map(link),
+                prev: page.prev.map(link),
+            },
+            items: page.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use application::rate_limit::{Decision, Quota, RateLimiter};
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, MatchedPath, Request, State},
+    http::{
+        HeaderMap, HeaderName, HeaderValue, Method, StatusCode,
+        header::RETRY_AFTER, request::Parts,
+    },
+    middleware::Next,
+    response::{IntoResponse as _, Response},
+};
+use futures_util::{FutureExt as _, future::BoxFuture};
+
+use crate::errors::JsonError;
+
+pub const LIMIT_HEADER: &str = "ratelimit-limit";
+pub const REMAINING_HEADER: &str = "ratelimit-remaining";
+pub const RESET_HEADER: &str = "ratelimit-reset";
+
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+pub trait RateLimitSubject {
+    fn rate_limit_key(&self) -> String;
+}
+
+type SubjectExtractor<M> = Arc<
+    dyn for<'a> Fn(&'a mut Parts, &'a M) -> BoxFuture<'a, Option<String>>
+        + Send
+        + Sync,
+>;
+
+pub enum KeyBy<M> {
+    ClientIp,
+    Header(HeaderName),
+    Subject(SubjectExtractor<M>),
+}
+
+impl<M> KeyBy<M>
+where
+    M: Sync + 'static,
+{
+    #[must_use]
+    pub const fn client_ip() -> Self {
+        Self::ClientIp
+    }
+
+    #[must_use]
+    pub const fn header(name: HeaderName) -> Self {
+        Self::Header(name)
+    }
+
+    #[must_use]
+    pub fn subject<E>() -> Self
+    where
+        E: FromRequestParts<M> + RateLimitSubject + 'static,
+    {
+        Self::Subject(Arc::new(extract_subject::<E, M>))
+    }
+}
+
+impl<M> Clone for KeyBy<M> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::ClientIp => Self::ClientIp,
+            Self::Header(name) => Self::Header(name.clone()),
+            Self::Subject(extractor) => Self::Subject(Arc::clone(extractor)),
+        }
+    }
+}
+
+fn extract_subject<'a, E, M>(
+    parts: &'a mut Parts,
+    state: &'a M,
+) -> BoxFuture<'a, Option<String>>
+where
+    E: FromRequestParts<M> + RateLimitSubject + 'static,
+    M: Sync,
+{
+    async move {
+        E::from_request_parts(parts, state)
+            .await
+            .ok()
+            .map(|subject| subject.rate_limit_key())
+    }
+    .boxed()
+}
+
+struct Rule<M> {
+    method: Method,
+    path: &'static str,
+    quota: Quota,
+    key: KeyBy<M>,
+}
+
+pub struct RateLimit<M> {
+    limiter: RateLimiter,
+    rules: Vec<Rule<M>>,
+    trusted_proxy_hops: usize,
+}
+
+impl<M> RateLimit<M> {
+    #[must_use]
+    pub const fn new(limiter: RateLimiter) -> Self {
+        Self {
+            limiter,
+            rules: Vec::new(),
+            trusted_proxy_hops: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn route(
+        mut self,
+        method: Method,
+        path: &'static str,
+        quota: Quota,
+        key: KeyBy<M>,
+    ) -> Self {
+        self.rules.push(Rule {
+            method,
+            path,
+            quota,
+            key,
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn trusted_proxy_hops(mut self, hops: usize) -> Self {
+        self.trusted_proxy_hops = hops;
+        self
+    }
+
+    pub(crate) fn into_state(self, app: M) -> RateLimitState<M> {
+        RateLimitState {
+            limiter: self.limiter,
+            rules: self.rules.into(),
+            trusted_proxy_hops: self.trusted_proxy_hops,
+            app,
+        }
+    }
+}
+
+pub(crate) struct RateLimitState<M> {
+    limiter: RateLimiter,
+    rules: Arc<[Rule<M>]>,
+    trusted_proxy_hops: usize,
+    app: M,
+}
+
+impl<M> Clone for RateLimitState<M>
+where
+    M: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            limiter: self.limiter.clone(),
+            rules: Arc::clone(&self.rules),
+            trusted_proxy_hops: self.trusted_proxy_hops,
+            app: self.app.clone(),
+        }
+    }
+}
+
+pub(crate) async fn enforce<M>(
+    State(state): State<RateLimitState<M>>,
+    request: Request,
+    next: Next,
+) -> Response
+where
+    M: Clone + Send + Sync + 'static,
+{
+    let rule = request.extensions().get::<MatchedPath>().and_then(|path| {
+        state.rules.iter().find(|rule| {
+            rule.method == request.method() && rule.path == path.as_str()
+        })
+    });
+
+    let Some(rule) = rule else {
+        return next.run(request).await;
+    };
+
+    let (mut parts, body) = request.into_parts();
+
+    let subject = match &rule.key {
+        KeyBy::ClientIp => None,
+        KeyBy::Header(name) => parts
+            .headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned),
+        KeyBy::Subject(extract) => extract(&mut parts, &state.app).await,
+    }
+    .or_else(|| client_ip(&parts, state.trusted_proxy_hops));
+
+    let request = Request::from_parts(parts, body);
+
+    let Some(subject) = subject else {
+        return next.run(request).await;
+    };
+
+    let key = format!("{} {}:{subject}", rule.method, rule.path);
+    let decision = state.limiter.hit(&key, rule.quota).await;
+
+    if !decision.allowed {
+        tracing::info!(%key, "rate limit exceeded");
+
+        let mut response = JsonError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            "TOO_MANY_REQUESTS",
+            "rate limit exceeded, retry later",
+        )
+        .into_response();
+
+        let headers = response.headers_mut();
+        insert_headers(headers, &decision);
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from(seconds(decision.reset_after)),
+        );
+
+        return response;
+    }
+
+    let mut response = next.run(request).await;
+    insert_headers(response.headers_mut(), &decision);
+    response
+}
+
+// Entries left of the ones appended by our own proxies are client-supplied.
+pub(crate) fn client_ip(
+    parts: &Parts,
+    trusted_proxy_hops: usize,
+) -> Option<String> {
+    let forwarded = |hops: usize| {
+        parts
+            .headers
+            .get_all(FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .rev()
+            .nth(hops.saturating_sub(1))?
+            .parse::<IpAddr>()
+            .ok()
+    };
+
+    (trusted_proxy_hops > 0)
+        .then(|| forwarded(trusted_proxy_hops))
+        .flatten()
+        .or_else(|| {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip())
+        })
+        .map(|ip| ip.to_string())
+}
+
+fn insert_headers(headers: &mut HeaderMap, decision: &Decision) {
+    headers.insert(
+        HeaderName::from_static(LIMIT_HEADER),
+        HeaderValue::from(decision.limit),
+    );
+    headers.insert(
+        HeaderName::from_static(REMAINING_HEADER),
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        HeaderName::from_static(RESET_HEADER),
+        HeaderValue::from(seconds(decision.reset_after)),
+    );
+}
+
+fn seconds(duration: Duration) -> u64 {
+    let rounding = u64::from(duration.subsec_nanos() > 0);
+    duration.as_secs().saturating_add(rounding)
+}
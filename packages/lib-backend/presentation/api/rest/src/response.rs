@@ -1,8 +1,11 @@
-use axum::http::{Response, StatusCode};
+use axum::http::{HeaderValue, Response, StatusCode, header::CACHE_CONTROL};
 
 pub trait ResponseExt {
     #[must_use]
     fn with_status(self, status: StatusCode) -> Self;
+
+    #[must_use]
+    fn no_store(self) -> Self;
 }
 
 impl<T> ResponseExt for Response<T> {
@@ -10,4 +13,10 @@ impl<T> ResponseExt for Response<T> {
         *self.status_mut() = status;
         self
     }
+
+    fn no_store(mut self) -> Self {
+        self.headers_mut()
+            .insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+        self
+    }
 }
@@ -1,12 +1,11 @@
-#![expect(
-    clippy::expect_used,
-    reason = "startup path: failing fast here is intended"
-)]
-
-use std::net::SocketAddr;
+use std::{io, net::SocketAddr};
 
+use application::{
+    clock::{SharedClock, SystemClock},
+    shutdown::Shutdown,
+};
 use axum::{Router, middleware::from_fn_with_state};
-use tokio::{net::TcpListener, signal};
+use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::{
     cors::CorsLayer,
@@ -24,9 +23,12 @@ use {
 
 use super::{
     errors::envelope::ErrorEnvelope,
+    feature_flag::{self, FeatureGate},
+    idempotency::{self, Idempotency},
     mask::{self, ServerErrorMasking},
     negotiate::{self, BodyEncoder, ResponseFormat},
     panic_handler::PanicHandler,
+    rate_limit::{self, RateLimit},
     request_id::{self, RequestIdPolicy},
     routes::{fallback_404, fallback_405},
     tracing::{
@@ -44,6 +46,10 @@ where
     pub response_format: ResponseFormat,
     pub request_id_policy: RequestIdPolicy,
     pub mask_server_errors: ServerErrorMasking,
+    pub idempotency: Option<Idempotency>,
+    pub rate_limit: Option<RateLimit<M>>,
+    pub feature_gate: Option<FeatureGate<M>>,
+    pub clock: SharedClock,
     #[cfg(feature = "openapi")]
     pub openapi: Option<OpenApi>,
 }
@@ -60,6 +66,10 @@ where
             response_format: ResponseFormat::default(),
             request_id_policy: RequestIdPolicy::default(),
             mask_server_errors: ServerErrorMasking::Disabled,
+            idempotency: None,
+            rate_limit: None,
+            feature_gate: None,
+            clock: SystemClock::shared(),
             #[cfg(feature = "openapi")]
             openapi: None,
         }
@@ -89,6 +99,30 @@ where
         self
     }
 
+    #[must_use]
+    pub fn with_idempotency(mut self, idempotency: Idempotency) -> Self {
+        self.idempotency = Some(idempotency);
+        self
+    }
+
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit<M>) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    #[must_use]
+    pub fn with_feature_gate(mut self, feature_gate: FeatureGate<M>) -> Self {
+        self.feature_gate = Some(feature_gate);
+        self
+    }
+
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     #[must_use]
     pub fn with_envelope<E>(mut self, envelope: E) -> Self
     where
@@ -114,16 +148,47 @@ where
         self
     }
 
-    fn router(router: Router<M>, modules: M) -> Router<()> {
+    fn router(
+        router: Router<M>,
+        modules: M,
+        rate_limit: Option<RateLimit<M>>,
+        feature_gate: Option<FeatureGate<M>>,
+    ) -> Router<()> {
+        let router = match rate_limit {
+            Some(rate_limit) => router.route_layer(from_fn_with_state(
+                rate_limit.into_state(modules.clone()),
+                rate_limit::enforce::<M>,
+            )),
+            None => router,
+        };
+
+        let router = match feature_gate {
+            Some(feature_gate) => router.route_layer(from_fn_with_state(
+                feature_gate.into_state(modules.clone()),
+                feature_flag::hide_disabled::<M>,
+            )),
+            None => router,
+        };
+
         Router::new().merge(router.with_state(modules))
     }
 
     pub fn build(self) -> RestApi {
         #[cfg(feature = "openapi")]
-        let mut router = Self::router(self.router, self.modules);
+        let mut router = Self::router(
+            self.router,
+            self.modules,
+            self.rate_limit,
+            self.feature_gate,
+        );
 
         #[cfg(not(feature = "openapi"))]
-        let router = Self::router(self.router, self.modules);
+        let router = Self::router(
+            self.router,
+            self.modules,
+            self.rate_limit,
+            self.feature_gate,
+        );
 
         #[cfg(feature = "openapi")]
         if let Some(openapi) = self.openapi {
@@ -156,12 +221,15 @@ where
             .layer(
                 TraceLayer::new_for_http()
                     .make_span_with(
-                        AxumOtelSpanCreator::new().level(Level::INFO),
+                        AxumOtelSpanCreator::new()
+                            .level(Level::INFO)
+                            .clock(self.clock),
                     )
                     .on_response(AxumOtelOnResponse::new().level(Level::INFO))
                     .on_failure(AxumOtelOnFailure::new()),
             )
             .layer(self.cors)
+            .layer(from_fn_with_state(self.idempotency, idempotency::enforce))
             .layer(PanicHandler::layer());
 
         let router = router
@@ -175,6 +243,22 @@ where
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum RestApiError {
+    #[error("failed to bind {addr}: {source}")]
+    Bind {
+        addr: SocketAddr,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to resolve the local address: {0}")]
+    LocalAddr(#[source] io::Error),
+
+    #[error("server stopped with an error: {0}")]
+    Serve(#[source] io::Error),
+}
+
 pub struct RestApi {
     pub(crate) router: Router,
 }
@@ -187,60 +271,56 @@ impl RestApi {
         RestApiBuilder::new(router, modules)
     }
 
+    #[must_use]
+    pub fn into_router(self) -> Router {
+        self.router
+    }
+
     #[must_use]
     pub fn is_openapi_route(path: &str) -> bool {
         ["/openapi", "/openapi.json"].contains(&path)
     }
 
-    pub async fn run(self, addr: SocketAddr) {
-        let listener = TcpListener::bind(addr)
-            .await
-            .expect("TcpListener cannot bind.");
-        self.serve(listener).await;
+    pub async fn run(
+        self,
+        addr: SocketAddr,
+        shutdown: &Shutdown,
+    ) -> Result<(), RestApiError> {
+        let listener = TcpListener::bind(addr).await.map_err(|source| {
+            RestApiError::Bind {
+                addr,
+                source,
+            }
+        })?;
+
+        self.serve(listener, shutdown).await
     }
 
-    pub async fn serve(self, listener: TcpListener) {
-        self.serve_with_shutdown(listener, Self::shutdown_signal())
-            .await;
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: &Shutdown,
+    ) -> Result<(), RestApiError> {
+        self.serve_with_shutdown(listener, shutdown.signal()).await
     }
 
-    pub async fn serve_with_shutdown<F>(self, listener: TcpListener, signal: F)
+    pub async fn serve_with_shutdown<F>(
+        self,
+        listener: TcpListener,
+        signal: F,
+    ) -> Result<(), RestApiError>
     where
         F: std::future::Future<Output = ()> + Send + 'static,
     {
-        let app = self.router.into_make_service();
-        let addr = listener
-            .local_addr()
-            .expect("TcpListener must have a local address.");
+        let app = self
+            .router
+            .into_make_service_with_connect_info::<SocketAddr>();
+        let addr = listener.local_addr().map_err(RestApiError::LocalAddr)?;
         tracing::info!("Server is listening on {}", addr);
 
         axum::serve(listener, app)
             .with_graceful_shutdown(signal)
             .await
-            .expect("Server cannot launch.");
-    }
-
-    async fn shutdown_signal() {
-        let ctrl_c = async {
-            signal::ctrl_c()
-                .await
-                .expect("failed to install Ctrl+C handler");
-        };
-
-        #[cfg(unix)]
-        let terminate = async {
-            signal::unix::signal(signal::unix::SignalKind::terminate())
-                .expect("failed to install signal handler")
-                .recv()
-                .await;
-        };
-
-        #[cfg(not(unix))]
-        let terminate = std::future::pending::<()>();
-
-        tokio::select! {
-            () = ctrl_c => {},
-            () = terminate => {},
-        }
+            .map_err(RestApiError::Serve)
     }
 }
@@ -1,5 +1,6 @@
 use std::{net::SocketAddr, str::FromStr as _};
 
+use application::clock::{SharedClock, SystemClock};
 use axum::{
     extract::ConnectInfo,
     http::{self, uri::PathAndQuery},
@@ -16,16 +17,18 @@ use uuid::Uuid;
 
 use crate::errors::RequestMeta;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct AxumOtelSpanCreator {
     level: Level,
+    clock: SharedClock,
 }
 
 impl AxumOtelSpanCreator {
     #[must_use]
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             level: Level::TRACE,
+            clock: SystemClock::shared(),
         }
     }
 
@@ -34,6 +37,12 @@ impl AxumOtelSpanCreator {
         self.level = level;
         self
     }
+
+    #[must_use]
+    pub fn clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
 }
 
 impl Default for AxumOtelSpanCreator {
@@ -52,10 +61,11 @@ impl<B> MakeSpan<B> for AxumOtelSpanCreator {
             .and_then(|value| Uuid::from_str(value).ok())
             .unwrap_or_else(Uuid::now_v7);
 
-        let client_ip = request
+        let client_addr = request
             .extensions()
             .get::<ConnectInfo<SocketAddr>>()
-            .map(|ConnectInfo(ip)| tracing::field::debug(ip));
+            .map(|ConnectInfo(addr)| *addr);
+        let client_ip = client_addr.map(tracing::field::debug);
 
         #[cfg(feature = "opentelemetry")]
         let span_name = format!("{http_method} {http_route}");
@@ -102,6 +112,8 @@ impl<B> MakeSpan<B> for AxumOtelSpanCreator {
                 span_ref.extensions_mut().insert(RequestMeta {
                     http_route,
                     request_id: Some(request_id),
+                    client_ip: client_addr.map(|addr| addr.ip()),
+                    clock: self.clock.clone(),
                 });
             }
         });
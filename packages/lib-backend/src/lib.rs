@@ -67,6 +67,8 @@ pub use redact;
     feature = "presentation",
 ))]
 pub use tap;
+#[cfg(feature = "testkit")]
+pub use testkit;
 #[cfg(feature = "bootstrap")]
 pub use tower;
 #[cfg(feature = "bootstrap")]
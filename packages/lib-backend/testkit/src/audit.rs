@@ -0,0 +1,47 @@
+use application::audit::{AuditFilter, AuditRecord, AuditStore};
+use domain::pagination::{Page, PageRequest};
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+
+use crate::table::InMemoryTable;
+
+#[derive(Clone, Default)]
+pub struct InMemoryAuditStore {
+    records: InMemoryTable<AuditRecord>,
+}
+
+impl InMemoryAuditStore {
+    #[must_use]
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.records.all()
+    }
+}
+
+impl AuditStore for InMemoryAuditStore {
+    fn append(&self, record: AuditRecord) -> BoxFuture<'_, Result<(), String>> {
+        self.records.insert(record.id, record);
+
+        future::ready(Ok(())).boxed()
+    }
+
+    fn query(
+        &self,
+        filter: AuditFilter,
+        request: PageRequest,
+    ) -> BoxFuture<'_, Result<Page<AuditRecord>, String>> {
+        let page = self.records.page_where(&request, |record| {
+            filter.actor.is_none_or(|actor| record.actor.id() == Some(actor))
+                && filter
+                    .action
+                    .as_ref()
+                    .is_none_or(|action| record.action == *action)
+                && filter
+                    .outcome
+                    .is_none_or(|outcome| record.outcome == outcome)
+        });
+
+        future::ready(Ok(page)).boxed()
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    any::{Any, TypeId, type_name},
+    collections::HashMap,
+    sync::Arc,
+};
+
+use application::{
+    audit::AuditLog,
+    authorization::Policy,
+    clock::{SharedClock, TestClock},
+    feature_flag::FeatureFlags,
+    id::{SeededIdGenerator, SharedIdGenerator},
+    impl_has,
+    outbox::SharedOutbox,
+    transaction::HasTransactionManager,
+};
+
+use crate::{
+    audit::InMemoryAuditStore, outbox::InMemoryOutbox,
+    transaction::InMemoryTransactionManager,
+};
+
+pub const DEFAULT_SEED: u64 = 0;
+
+type Extensions = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+#[derive(Clone)]
+pub struct TestContainer {
+    clock: TestClock,
+    shared_clock: SharedClock,
+    id_generator: SharedIdGenerator,
+    audit_store: InMemoryAuditStore,
+    audit: AuditLog,
+    policy: Policy,
+    feature_flags: FeatureFlags,
+    outbox: InMemoryOutbox,
+    shared_outbox: SharedOutbox,
+    transactions: InMemoryTransactionManager,
+    extensions: Arc<Extensions>,
+}
+
+impl TestContainer {
+    #[must_use]
+    pub fn builder() -> TestContainerBuilder {
+        TestContainerBuilder::new()
+    }
+
+    #[must_use]
+    pub const fn clock(&self) -> &TestClock {
+        &self.clock
+    }
+
+    #[must_use]
+    pub const fn audit_store(&self) -> &InMemoryAuditStore {
+        &self.audit_store
+    }
+
+    #[must_use]
+    pub const fn outbox(&self) -> &InMemoryOutbox {
+        &self.outbox
+    }
+
+    #[must_use]
+    pub const fn transactions(&self) -> &InMemoryTransactionManager {
+        &self.transactions
+    }
+
+    #[must_use]
+    pub fn try_extension<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.extensions
+            .get(&TypeId::of::<T>())
+            .and_then(|extension| extension.downcast_ref())
+    }
+
+    #[must_use]
+    pub fn extension<T>(&self) -> &T
+    where
+        T: Any,
+    {
+        self.try_extension().unwrap_or_else(|| {
+            panic!(
+                "`{}` is not registered in the test container",
+                type_name::<T>()
+            )
+        })
+    }
+}
+
+impl_has! {
+    struct: TestContainer,
+    SharedClock: |s| &s.shared_clock,
+    SharedIdGenerator: |s| &s.id_generator,
+    AuditLog: |s| &s.audit,
+    Policy: |s| &s.policy,
+    FeatureFlags: |s| &s.feature_flags,
+    SharedOutbox: |s| &s.shared_outbox,
+}
+
+impl HasTransactionManager for TestContainer {
+    type TransactionManager = InMemoryTransactionManager;
+
+    fn transaction_manager(&self) -> &Self::TransactionManager {
+        &self.transactions
+    }
+}
+
+pub struct TestContainerBuilder {
+    clock: TestClock,
+    id_generator: SharedIdGenerator,
+    audit_store: InMemoryAuditStore,
+    policy: Policy,
+    feature_flags: FeatureFlags,
+    outbox: InMemoryOutbox,
+    transactions: InMemoryTransactionManager,
+    extensions: Extensions,
+}
+
+impl TestContainerBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            clock: TestClock::default(),
+            id_generator: SeededIdGenerator::shared(DEFAULT_SEED),
+            audit_store: InMemoryAuditStore::default(),
+            policy: Policy::default(),
+            feature_flags: FeatureFlags::default(),
+            outbox: InMemoryOutbox::new(),
+            transactions: InMemoryTransactionManager::new(),
+            extensions: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn clock(mut self, clock: TestClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[must_use]
+    pub fn id_generator(mut self, id_generator: SharedIdGenerator) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    #[must_use]
+    pub fn audit_store(mut self, audit_store: InMemoryAuditStore) -> Self {
+        self.audit_store = audit_store;
+        self
+    }
+
+    #[must_use]
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    #[must_use]
+    pub fn feature_flags(mut self, feature_flags: FeatureFlags) -> Self {
+        self.feature_flags = feature_flags;
+        self
+    }
+
+    #[must_use]
+    pub fn outbox(mut self, outbox: InMemoryOutbox) -> Self {
+        self.outbox = outbox;
+        self
+    }
+
+    #[must_use]
+    pub fn transactions(
+        mut self,
+        transactions: InMemoryTransactionManager,
+    ) -> Self {
+        self.transactions = transactions;
+        self
+    }
+
+    #[must_use]
+    pub fn with<T>(mut self, extension: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        self.extensions
+            .insert(TypeId::of::<T>(), Arc::new(extension));
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> TestContainer {
+        let shared_clock = self.clock.shared();
+        let audit = AuditLog::new(self.audit_store.clone())
+            .clock(shared_clock.clone())
+            .id_generator(self.id_generator.clone());
+        let shared_outbox = self.outbox.shared();
+
+        TestContainer {
+            clock: self.clock,
+            shared_clock,
+            id_generator: self.id_generator,
+            audit_store: self.audit_store,
+            audit,
+            policy: self.policy,
+            feature_flags: self.feature_flags,
+            outbox: self.outbox,
+            shared_outbox,
+            transactions: self.transactions,
+            extensions: Arc::new(self.extensions),
+        }
+    }
+}
+
+impl Default for TestContainerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use application::{
+        audit::{Actor, AuditAction, AuditEvent, HasAuditExt as _},
+        clock::{Clock as _, HasClockExt as _, TestClock},
+        id::{IdGenerator as _, SeededIdGenerator},
+    };
+
+    use super::TestContainer;
+
+    #[test]
+    fn resolves_registered_extensions() {
+        let container = TestContainer::builder().with(42_u32).build();
+
+        assert_eq!(
+            container.try_extension::<u32>(),
+            Some(&42),
+            "extension is registered"
+        );
+        assert!(
+            container.try_extension::<u64>().is_none(),
+            "unregistered extension is missing"
+        );
+    }
+
+    #[test]
+    fn shares_the_configured_clock() {
+        let clock = TestClock::default();
+        let container = TestContainer::builder().clock(clock.clone()).build();
+
+        clock.advance(Duration::from_secs(60));
+
+        assert_eq!(container.now(), clock.now(), "clock is shared");
+    }
+
+    #[tokio::test]
+    async fn stamps_audit_records_with_the_configured_ids() {
+        let container = TestContainer::builder()
+            .id_generator(SeededIdGenerator::shared(7))
+            .build();
+
+        container
+            .audit(AuditEvent::new(Actor::System, AuditAction::new("test")))
+            .await;
+
+        let ids = container
+            .audit_store()
+            .records()
+            .into_iter()
+            .map(|record| record.id.value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ids,
+            [SeededIdGenerator::new(7).next_uuid()],
+            "audit ids come from the id generator"
+        );
+    }
+}
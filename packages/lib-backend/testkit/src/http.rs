@@ -0,0 +1,162 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use axum::{
+    Router,
+    body::{self, Body, Bytes},
+    extract::connect_info::MockConnectInfo,
+    http::{
+        self, HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode,
+        header,
+    },
+};
+use rest::startup::RestApi;
+use serde::{Serialize, de::DeserializeOwned};
+use tower::ServiceExt as _;
+#[cfg(feature = "openapi")]
+use utoipa_axum::router::OpenApiRouter;
+
+pub const DEFAULT_CLIENT_ADDR: SocketAddr =
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 40_000);
+
+#[derive(thiserror::Error, Debug)]
+pub enum TestClientError {
+    #[error(transparent)]
+    Request(#[from] http::Error),
+
+    #[error(transparent)]
+    Body(#[from] axum::Error),
+
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+}
+
+#[derive(Clone)]
+pub struct TestClient {
+    router: Router,
+}
+
+impl TestClient {
+    #[must_use]
+    pub fn new(router: Router) -> Self {
+        Self {
+            router: router.layer(MockConnectInfo(DEFAULT_CLIENT_ADDR)),
+        }
+    }
+
+    #[cfg(feature = "openapi")]
+    #[must_use]
+    pub fn from_openapi<S>(router: OpenApiRouter<S>, state: S) -> Self
+    where
+        S: Clone + Send + Sync + 'static,
+    {
+        let (router, _) = router.split_for_parts();
+        Self::new(router.with_state(state))
+    }
+
+    #[must_use]
+    pub fn from_rest_api(api: RestApi) -> Self {
+        Self::new(api.into_router())
+    }
+
+    #[must_use]
+    pub fn request(&self, method: Method, uri: &str) -> TestRequest {
+        TestRequest {
+            router: self.router.clone(),
+            builder: Request::builder().method(method).uri(uri),
+            body: Ok(Body::empty()),
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, uri: &str) -> TestRequest {
+        self.request(Method::GET, uri)
+    }
+
+    #[must_use]
+    pub fn post(&self, uri: &str) -> TestRequest {
+        self.request(Method::POST, uri)
+    }
+
+    #[must_use]
+    pub fn put(&self, uri: &str) -> TestRequest {
+        self.request(Method::PUT, uri)
+    }
+
+    #[must_use]
+    pub fn patch(&self, uri: &str) -> TestRequest {
+        self.request(Method::PATCH, uri)
+    }
+
+    #[must_use]
+    pub fn delete(&self, uri: &str) -> TestRequest {
+        self.request(Method::DELETE, uri)
+    }
+}
+
+pub struct TestRequest {
+    router: Router,
+    builder: http::request::Builder,
+    body: Result<Body, serde_json::Error>,
+}
+
+impl TestRequest {
+    #[must_use]
+    pub fn header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.builder = self.builder.header(name, value);
+        self
+    }
+
+    #[must_use]
+    pub fn bearer(self, token: &str) -> Self {
+        self.header(header::AUTHORIZATION, format!("Bearer {token}"))
+    }
+
+    #[must_use]
+    pub fn json<T>(mut self, body: &T) -> Self
+    where
+        T: Serialize,
+    {
+        self.body = serde_json::to_vec(body).map(Body::from);
+        self.header(header::CONTENT_TYPE, "application/json")
+    }
+
+    pub async fn send(self) -> Result<TestResponse, TestClientError> {
+        let request = self.builder.body(self.body?)?;
+        let Ok(response) = self.router.oneshot(request).await;
+
+        let (parts, body) = response.into_parts();
+
+        Ok(TestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body: body::to_bytes(body, usize::MAX).await?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl TestResponse {
+    pub fn json<T>(&self) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_slice(&self.body)
+    }
+
+    #[must_use]
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
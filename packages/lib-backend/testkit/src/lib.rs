@@ -0,0 +1,6 @@
+pub mod audit;
+pub mod container;
+pub mod http;
+pub mod outbox;
+pub mod table;
+pub mod transaction;
@@ -0,0 +1,87 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+use application::outbox::{JobEntry, Outbox, OutboxEntry, SharedOutbox};
+use futures_util::{
+    FutureExt as _,
+    future::{self, BoxFuture},
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryOutbox {
+    entries: Arc<RwLock<Vec<OutboxEntry>>>,
+    jobs: Arc<RwLock<Vec<JobEntry>>>,
+}
+
+impl InMemoryOutbox {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn shared(&self) -> SharedOutbox {
+        Arc::new(self.clone())
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> Vec<OutboxEntry> {
+        self.entries
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    #[must_use]
+    pub fn jobs(&self) -> Vec<JobEntry> {
+        self.jobs
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Outbox for InMemoryOutbox {
+    fn append(&self, entry: OutboxEntry) -> BoxFuture<'_, Result<(), String>> {
+        self.entries
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(entry);
+
+        future::ready(Ok(())).boxed()
+    }
+
+    fn enqueue_job(&self, job: JobEntry) -> BoxFuture<'_, Result<(), String>> {
+        self.jobs
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(job);
+
+        future::ready(Ok(())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use application::outbox::OutboxEntry;
+    use serde_json::json;
+
+    use super::InMemoryOutbox;
+
+    #[tokio::test]
+    async fn shared_outbox_records_into_the_same_store() {
+        let outbox = InMemoryOutbox::new();
+
+        let appended = outbox
+            .shared()
+            .append(OutboxEntry {
+                aggregate_id: "user".to_owned(),
+                event_type: "user.created",
+                payload: json!({}),
+            })
+            .await;
+
+        assert!(appended.is_ok(), "append succeeds");
+        assert_eq!(outbox.entries().len(), 1, "entry is recorded");
+        assert!(outbox.jobs().is_empty(), "no jobs were enqueued");
+    }
+}
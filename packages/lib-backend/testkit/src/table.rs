@@ -0,0 +1,122 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use derive_where::derive_where;
+use domain::{
+    Id,
+    pagination::{Direction, Page, PageRequest},
+};
+use uuid::Uuid;
+
+#[derive_where(Clone, Default)]
+pub struct InMemoryTable<T> {
+    rows: Arc<RwLock<BTreeMap<Uuid, T>>>,
+}
+
+impl<T> InMemoryTable<T>
+where
+    T: Clone,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<E>(&self, id: Id<E>, row: T) -> Option<T> {
+        self.rows
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(id.value, row)
+    }
+
+    pub fn remove<E>(&self, id: Id<E>) -> Option<T> {
+        self.rows
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&id.value)
+    }
+
+    #[must_use]
+    pub fn get<E>(&self, id: Id<E>) -> Option<T> {
+        self.rows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&id.value)
+            .cloned()
+    }
+
+    #[must_use]
+    pub fn find<F>(&self, predicate: F) -> Option<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.rows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .find(|row| predicate(row))
+            .cloned()
+    }
+
+    #[must_use]
+    pub fn all(&self) -> Vec<T> {
+        self.rows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.read().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn page(&self, request: &PageRequest) -> Page<T> {
+        self.page_where(request, |_| true)
+    }
+
+    #[must_use]
+    pub fn page_where<F>(&self, request: &PageRequest, predicate: F) -> Page<T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let rows = self.rows.read().unwrap_or_else(PoisonError::into_inner);
+        let fetch_size =
+            usize::try_from(request.limit.fetch_size()).unwrap_or(usize::MAX);
+        let position = request.cursor.map(|cursor| cursor.position);
+
+        let matching = rows
+            .iter()
+            .filter(|(_, row)| predicate(row))
+            .map(|(id, row)| (*id, row.clone()));
+
+        let page: Vec<(Uuid, T)> = match request.direction() {
+            Direction::Forward => matching
+                .filter(|(id, _)| {
+                    position.is_none_or(|position| *id > position)
+                })
+                .take(fetch_size)
+                .collect(),
+            Direction::Backward => matching
+                .rev()
+                .filter(|(id, _)| {
+                    position.is_none_or(|position| *id < position)
+                })
+                .take(fetch_size)
+                .collect(),
+        };
+
+        Page::from_keyset(request, page, |(id, _)| Id::<T>::new(*id))
+            .map(|(_, row)| row)
+    }
+}
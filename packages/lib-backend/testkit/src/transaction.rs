@@ -0,0 +1,195 @@
+use std::sync::{
+    Arc, PoisonError, RwLock,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use application::transaction::{
+    Transaction, TransactionError, TransactionManager,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransactionOutcome {
+    Committed,
+    RolledBack,
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryTransactionManager {
+    outcomes: Arc<RwLock<Vec<TransactionOutcome>>>,
+    begun: Arc<AtomicUsize>,
+}
+
+impl InMemoryTransactionManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn begun(&self) -> usize {
+        self.begun.load(Ordering::Acquire)
+    }
+
+    #[must_use]
+    pub fn outcomes(&self) -> Vec<TransactionOutcome> {
+        self.outcomes
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl TransactionManager for InMemoryTransactionManager {
+    type Transaction = InMemoryTransaction;
+
+    async fn begin(&self) -> Result<Self::Transaction, TransactionError> {
+        self.begun.fetch_add(1, Ordering::AcqRel);
+
+        Ok(InMemoryTransaction {
+            outcomes: Arc::clone(&self.outcomes),
+            finished: Arc::default(),
+        })
+    }
+}
+
+/// Records whether work committed or rolled back, but does not undo it:
+/// writes to in-memory tables and the outbox stay visible after a rollback.
+/// Assert on [`InMemoryTransactionManager::outcomes`] instead.
+#[derive(Clone)]
+pub struct InMemoryTransaction {
+    outcomes: Arc<RwLock<Vec<TransactionOutcome>>>,
+    finished: Arc<AtomicBool>,
+}
+
+impl InMemoryTransaction {
+    fn finish(
+        &self,
+        outcome: TransactionOutcome,
+    ) -> Result<(), TransactionError> {
+        if self.finished.swap(true, Ordering::AcqRel) {
+            return Err(TransactionError::Finished);
+        }
+
+        self.outcomes
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(outcome);
+
+        Ok(())
+    }
+}
+
+impl Transaction for InMemoryTransaction {
+    async fn commit(&self) -> Result<(), TransactionError> {
+        self.finish(TransactionOutcome::Committed)
+    }
+
+    async fn rollback(&self) -> Result<(), TransactionError> {
+        self.finish(TransactionOutcome::RolledBack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use application::transaction::{
+        HasTransactionManager, TransactionError, UnitOfWork as _,
+        after_commit,
+    };
+
+    use super::{InMemoryTransactionManager, TransactionOutcome};
+
+    struct Deps(InMemoryTransactionManager);
+
+    impl HasTransactionManager for Deps {
+        type TransactionManager = InMemoryTransactionManager;
+
+        fn transaction_manager(&self) -> &Self::TransactionManager {
+            &self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn commits_successful_work_once() {
+        let deps = Deps(InMemoryTransactionManager::new());
+
+        let result = deps
+            .transaction(async {
+                deps.transaction(async { Ok::<_, TransactionError>(1) })
+                    .await
+            })
+            .await;
+
+        assert!(matches!(result, Ok(1)), "work result is returned");
+        assert_eq!(deps.0.begun(), 1, "nested work joins the transaction");
+        assert_eq!(
+            deps.0.outcomes(),
+            [TransactionOutcome::Committed],
+            "transaction is committed"
+        );
+    }
+
+    #[tokio::test]
+    async fn rolls_back_failed_work() {
+        let deps = Deps(InMemoryTransactionManager::new());
+
+        let result = deps
+            .transaction(async {
+                Err::<(), _>(TransactionError::Begin("boom".to_owned()))
+            })
+            .await;
+
+        assert!(result.is_err(), "work error is returned");
+        assert_eq!(
+            deps.0.outcomes(),
+            [TransactionOutcome::RolledBack],
+            "transaction is rolled back"
+        );
+    }
+
+    #[tokio::test]
+    async fn runs_after_commit_hooks_only_once_committed() {
+        let deps = Deps(InMemoryTransactionManager::new());
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let hook = |runs: &Arc<AtomicUsize>| {
+            let runs = Arc::clone(runs);
+            async move {
+                runs.fetch_add(1, Ordering::AcqRel);
+            }
+        };
+
+        let committed = deps
+            .transaction(async {
+                after_commit(hook(&runs)).await;
+                assert_eq!(
+                    runs.load(Ordering::Acquire),
+                    0,
+                    "hook is deferred until commit"
+                );
+                Ok::<_, TransactionError>(())
+            })
+            .await;
+
+        assert!(committed.is_ok(), "work succeeds");
+        assert_eq!(runs.load(Ordering::Acquire), 1, "hook runs after commit");
+
+        let rolled_back = deps
+            .transaction(async {
+                after_commit(hook(&runs)).await;
+                Err::<(), _>(TransactionError::Begin("boom".to_owned()))
+            })
+            .await;
+
+        assert!(rolled_back.is_err(), "work fails");
+        assert_eq!(
+            runs.load(Ordering::Acquire),
+            1,
+            "hook is dropped on rollback"
+        );
+    }
+}